@@ -0,0 +1,28 @@
+use std::{env, path::PathBuf, process::ExitCode};
+use tellor_parachain_integration_tests::export_chain_specs;
+
+const USAGE: &str = "usage: tellor-chain-specs <output directory>";
+
+/// Exports raw chain specs for the relay chain and each parachain of the mock network to the given
+/// directory, so that local nodes can be started from the same genesis as the tests, along with the
+/// state of the evm parachain with the contracts deployed.
+fn main() -> ExitCode {
+    let mut args = env::args_os().skip(1);
+    let (Some(dir), None) = (args.next(), args.next()) else {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    };
+
+    match export_chain_specs(&PathBuf::from(dir)) {
+        Ok(paths) => {
+            for path in paths {
+                println!("{}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+use tellor_parachain_integration_tests::run_scenario_file;
+
+const USAGE: &str = "usage: tellor-scenarios <scenario file or directory>...";
+
+/// Runs the scenario files given as arguments, along with any `.yaml`, `.yml` or `.json` files
+/// within given directories, against the mock network, exiting with a failure if any scenario fails.
+fn main() -> ExitCode {
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .try_init();
+
+    let args: Vec<PathBuf> = env::args_os().skip(1).map(PathBuf::from).collect();
+    if args.is_empty() {
+        eprintln!("{USAGE}");
+        return ExitCode::FAILURE;
+    }
+
+    let mut files = Vec::new();
+    for path in &args {
+        if let Err(e) = collect(path, &mut files) {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut failed = Vec::new();
+    for file in &files {
+        match run_scenario_file(file) {
+            Ok(()) => println!("scenario {} ... ok", file.display()),
+            Err(e) => {
+                println!("scenario {} ... FAILED\n  {e}", file.display());
+                failed.push(file);
+            }
+        }
+    }
+
+    println!(
+        "\nscenario result: {}. {} passed; {} failed",
+        if failed.is_empty() { "ok" } else { "FAILED" },
+        files.len() - failed.len(),
+        failed.len()
+    );
+    if failed.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+// collects scenario files from the path, sorted by name when a directory
+fn collect(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    if !path.is_dir() {
+        files.push(path.to_path_buf());
+        return Ok(());
+    }
+    let mut entries: Vec<PathBuf> = fs::read_dir(path)
+        .map_err(|e| format!("could not read {}: {e}", path.display()))?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            matches!(
+                p.extension().and_then(|e| e.to_str()),
+                Some("yaml" | "yml" | "json")
+            )
+        })
+        .collect();
+    entries.sort();
+    files.extend(entries);
+    Ok(())
+}
@@ -0,0 +1,448 @@
+use crate::{
+    parachains::MAX_HRMP_MESSAGE_SIZE, relay_chain, AssetReserveParachain, EvmParachain,
+    OracleConsumerParachain, SecondOracleConsumerParachain,
+};
+use codec::{Decode, Encode};
+use frame_support::{sp_io, traits::GenesisBuild};
+use polkadot_parachain::primitives::{HeadData, ValidationCode};
+use polkadot_runtime_parachains::{
+    hrmp,
+    paras::{self, ParaGenesisArgs, ParaKind},
+};
+use serde::{Deserialize, Serialize};
+use sp_core::{
+    bytes,
+    storage::{well_known_keys, ChildInfo, Storage, StorageChild},
+};
+use sp_runtime::{
+    generic,
+    traits::{BlakeTwo256, Hash, Header},
+    StateVersion,
+};
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+use xcm_emulator::{ParaId, Parachain, TestExt};
+
+/// The id of the relay chain, as referenced by the parachain chain specs.
+pub(crate) const RELAY_CHAIN_ID: &str = "rococo_local_testnet";
+/// The capacity of each hrmp channel opened between the parachains at genesis.
+pub(crate) const HRMP_CHANNEL_MAX_CAPACITY: u32 = 8;
+
+/// A raw chain spec, as accepted by the `--chain` option of relay chain and parachain nodes.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ChainSpec {
+    pub(crate) name: String,
+    pub(crate) id: String,
+    pub(crate) chain_type: String,
+    pub(crate) boot_nodes: Vec<String>,
+    pub(crate) telemetry_endpoints: Option<()>,
+    pub(crate) protocol_id: Option<String>,
+    pub(crate) properties: Option<serde_json::Map<String, serde_json::Value>>,
+    #[serde(
+        rename = "relay_chain",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub(crate) relay_chain: Option<String>,
+    #[serde(rename = "para_id", default, skip_serializing_if = "Option::is_none")]
+    pub(crate) para_id: Option<u32>,
+    #[serde(default)]
+    pub(crate) code_substitutes: BTreeMap<String, String>,
+    pub(crate) genesis: Genesis,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub(crate) struct Genesis {
+    pub(crate) raw: RawGenesis,
+}
+
+/// Genesis storage, with hex-encoded keys and values.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RawGenesis {
+    pub(crate) top: BTreeMap<String, String>,
+    #[serde(default)]
+    pub(crate) children_default: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl ChainSpec {
+    fn new(name: &str, id: &str, storage: &Storage) -> Self {
+        Self {
+            name: name.to_string(),
+            id: id.to_string(),
+            chain_type: "Local".to_string(),
+            boot_nodes: Vec::new(),
+            telemetry_endpoints: None,
+            protocol_id: None,
+            properties: None,
+            relay_chain: None,
+            para_id: None,
+            code_substitutes: BTreeMap::new(),
+            genesis: Genesis {
+                raw: RawGenesis::from(storage),
+            },
+        }
+    }
+
+    fn parachain(name: &str, id: &str, para_id: ParaId, storage: &Storage) -> Self {
+        Self {
+            relay_chain: Some(RELAY_CHAIN_ID.to_string()),
+            para_id: Some(para_id.into()),
+            ..Self::new(name, id, storage)
+        }
+    }
+}
+
+impl From<&Storage> for RawGenesis {
+    fn from(storage: &Storage) -> Self {
+        let hex = |data: &BTreeMap<Vec<u8>, Vec<u8>>| {
+            data.iter()
+                .map(|(k, v)| (bytes::to_hex(k, false), bytes::to_hex(v, false)))
+                .collect()
+        };
+        Self {
+            top: hex(&storage.top),
+            children_default: storage
+                .children_default
+                .values()
+                .map(|child| {
+                    (
+                        bytes::to_hex(child.child_info.storage_key(), false),
+                        hex(&child.data),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+// a parachain genesis, along with the state version of its runtime
+struct ParachainGenesis {
+    name: &'static str,
+    id: &'static str,
+    para_id: ParaId,
+    storage: Storage,
+    state_version: StateVersion,
+}
+
+fn parachains() -> Vec<ParachainGenesis> {
+    vec![
+        ParachainGenesis {
+            name: "Statemine Local",
+            id: "statemine_local",
+            para_id: AssetReserveParachain::para_id(),
+            storage: integration_tests_common::constants::statemine::genesis(),
+            state_version: statemine_runtime::VERSION.state_version(),
+        },
+        ParachainGenesis {
+            name: "Moonbeam Local",
+            id: "moonbeam_local",
+            para_id: EvmParachain::para_id(),
+            storage: crate::parachains::evm::genesis(),
+            state_version: moonbeam_runtime::VERSION.state_version(),
+        },
+        ParachainGenesis {
+            name: "Oracle Consumer Local",
+            id: "oracle_consumer_local",
+            para_id: OracleConsumerParachain::para_id(),
+            storage: crate::parachains::oracle_consumer::genesis(),
+            state_version: oracle_consumer_runtime::VERSION.state_version(),
+        },
+        ParachainGenesis {
+            name: "Second Oracle Consumer Local",
+            id: "second_oracle_consumer_local",
+            para_id: SecondOracleConsumerParachain::para_id(),
+            storage: crate::parachains::oracle_consumer::second_genesis(),
+            state_version: oracle_consumer_runtime::VERSION.state_version(),
+        },
+    ]
+}
+
+/// The chain specs of the relay chain and each parachain of the mock network, built from the same
+/// genesis as the emulator. The relay chain genesis additionally registers each parachain, with
+/// hrmp channels opened between every pair, as the emulator routes messages between all
+/// parachains without channels.
+pub(crate) fn chain_specs() -> Vec<ChainSpec> {
+    let parachains = parachains();
+    let mut specs = vec![ChainSpec::new(
+        "Rococo Local Testnet",
+        RELAY_CHAIN_ID,
+        &relay_genesis(&parachains),
+    )];
+    specs.extend(
+        parachains
+            .iter()
+            .map(|p| ChainSpec::parachain(p.name, p.id, p.para_id, &p.storage)),
+    );
+    specs
+}
+
+/// The file within the export directory to which the state of the evm parachain, with the xcTRB
+/// asset created and the contracts deployed, is written as state pairs.
+pub(crate) const DEPLOYED_EVM_STATE: &str = "moonbeam_local.deployed.json";
+
+/// Writes the chain spec of each chain of the mock network to `<id>.json` within the directory,
+/// along with the state of the evm parachain once the contracts are deployed, which can be used as
+/// its genesis via `EVM_PARACHAIN_GENESIS`. Returns the paths written.
+pub fn export(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    fs::create_dir_all(dir).map_err(|e| format!("could not create {}: {e}", dir.display()))?;
+    // genesis is built within the emulator's externalities, so the chain specs are built on a new
+    // thread to avoid interfering with any network on the current thread
+    let specs = std::thread::spawn(chain_specs)
+        .join()
+        .map_err(|_| "could not build chain specs".to_string())?;
+    let mut paths = specs
+        .iter()
+        .map(|spec| {
+            let path = dir.join(format!("{}.json", spec.id));
+            let json = serde_json::to_string_pretty(spec)
+                .map_err(|e| format!("could not serialise {}: {e}", spec.id))?;
+            fs::write(&path, json)
+                .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+            Ok(path)
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    // likewise the contracts are deployed to a network on a new thread
+    let state = std::thread::spawn(deployed_evm_state)
+        .join()
+        .map_err(|_| "could not deploy contracts".to_string())?;
+    let path = dir.join(DEPLOYED_EVM_STATE);
+    let json = serde_json::to_string_pretty(&RawGenesis::from(&state).top)
+        .map_err(|e| format!("could not serialise {DEPLOYED_EVM_STATE}: {e}"))?;
+    fs::write(&path, json).map_err(|e| format!("could not write {}: {e}", path.display()))?;
+    paths.push(path);
+    Ok(paths)
+}
+
+// the state of the evm parachain of the current network once the contracts are deployed
+fn deployed_evm_state() -> Storage {
+    crate::parachains::scenario::Scenario::new().deploy();
+    // read outside of `execute_with`, so that the validation data it sets is not included
+    EvmParachain::ext_wrapper(state)
+}
+
+/// The top trie of the current externalities, as returned by the `state_getPairs` rpc of a live
+/// chain. Events are excluded, so that a chain started from the state does not include them.
+pub(crate) fn state() -> Storage {
+    let events: Vec<_> = [&b"Events"[..], b"EventCount", b"EventTopics"]
+        .into_iter()
+        .map(|item| frame_support::storage::storage_prefix(b"System", item))
+        .collect();
+    let mut top = BTreeMap::new();
+    let mut key = Vec::new();
+    while let Some(next) = sp_io::storage::next_key(&key) {
+        if !events.iter().any(|prefix| next.starts_with(prefix)) {
+            let value = sp_io::storage::get(&next).expect("key is present; qed");
+            top.insert(next.clone(), value.to_vec());
+        }
+        key = next;
+    }
+    Storage {
+        top,
+        children_default: Default::default(),
+    }
+}
+
+// the relay chain genesis, with the parachains registered and hrmp channels opened between them
+fn relay_genesis(parachains: &[ParachainGenesis]) -> Storage {
+    let mut storage = relay_chain::genesis();
+    <paras::GenesisConfig as GenesisBuild<rococo_runtime::Runtime>>::assimilate_storage(
+        &paras::GenesisConfig {
+            paras: parachains
+                .iter()
+                .map(|p| {
+                    (
+                        p.para_id,
+                        ParaGenesisArgs {
+                            genesis_head: genesis_head(&p.storage, p.state_version),
+                            validation_code: ValidationCode(
+                                p.storage
+                                    .top
+                                    .get(well_known_keys::CODE)
+                                    .expect("parachain genesis includes code; qed")
+                                    .clone(),
+                            ),
+                            para_kind: ParaKind::Parachain,
+                        },
+                    )
+                })
+                .collect(),
+        },
+        &mut storage,
+    )
+    .expect("parachains are valid; qed");
+
+    // the channels are not public fields, so the config is deserialised as from a chain spec
+    let channels: Vec<_> = parachains
+        .iter()
+        .flat_map(|sender| {
+            parachains
+                .iter()
+                .filter(move |recipient| recipient.para_id != sender.para_id)
+                .map(move |recipient| {
+                    (
+                        u32::from(sender.para_id),
+                        u32::from(recipient.para_id),
+                        HRMP_CHANNEL_MAX_CAPACITY,
+                        MAX_HRMP_MESSAGE_SIZE as u32,
+                    )
+                })
+        })
+        .collect();
+    let hrmp: hrmp::GenesisConfig =
+        serde_json::from_value(serde_json::json!({ "preopenHrmpChannels": channels }))
+            .expect("hrmp genesis config is valid; qed");
+    <hrmp::GenesisConfig as GenesisBuild<rococo_runtime::Runtime>>::assimilate_storage(
+        &hrmp,
+        &mut storage,
+    )
+    .expect("hrmp channels are valid; qed");
+    storage
+}
+
+/// The encoded genesis header of a parachain, as registered with the relay chain.
+pub(crate) fn genesis_head(storage: &Storage, state_version: StateVersion) -> HeadData {
+    let state_root = sp_io::TestExternalities::new(storage.clone())
+        .execute_with(|| sp_io::storage::root(state_version));
+    let header = generic::Header::<u32, BlakeTwo256>::new(
+        0,
+        BlakeTwo256::trie_root(Vec::new(), state_version),
+        sp_core::H256::from_slice(&state_root),
+        Default::default(),
+        Default::default(),
+    );
+    HeadData(header.encode())
+}
+
+/// Loads genesis storage from a file, either a raw chain spec or a key/value dump of a live chain
+/// (the result of a `state_getPairs` rpc call, as an array of key/value pairs or a json-rpc
+/// response containing them), with keys and values hex-encoded.
+pub(crate) fn load_genesis(path: &Path) -> Result<Storage, String> {
+    let json =
+        fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    let value: serde_json::Value = serde_json::from_str(&json)
+        .map_err(|e| format!("invalid json in {}: {e}", path.display()))?;
+    genesis_from_json(value).map_err(|e| format!("invalid genesis in {}: {e}", path.display()))
+}
+
+fn genesis_from_json(value: serde_json::Value) -> Result<Storage, String> {
+    use serde_json::Value;
+    let raw: RawGenesis = match value {
+        Value::Object(mut spec) if spec.contains_key("genesis") => {
+            let mut genesis = spec.remove("genesis").unwrap_or_default();
+            match genesis.get_mut("raw").map(Value::take) {
+                Some(raw) => serde_json::from_value(raw).map_err(|e| e.to_string())?,
+                None => return Err("chain spec is not raw, convert using --raw".to_string()),
+            }
+        }
+        Value::Object(mut response) if response.contains_key("result") => {
+            return genesis_from_json(response.remove("result").unwrap_or_default())
+        }
+        Value::Array(pairs) => RawGenesis {
+            top: serde_json::from_value::<Vec<(String, String)>>(Value::Array(pairs))
+                .map_err(|e| e.to_string())?
+                .into_iter()
+                .collect(),
+            children_default: BTreeMap::new(),
+        },
+        Value::Object(pairs) => RawGenesis {
+            top: serde_json::from_value(Value::Object(pairs)).map_err(|e| e.to_string())?,
+            children_default: BTreeMap::new(),
+        },
+        _ => return Err("expected a raw chain spec or key/value pairs".to_string()),
+    };
+    Storage::try_from(&raw)
+}
+
+impl TryFrom<&RawGenesis> for Storage {
+    type Error = String;
+
+    fn try_from(raw: &RawGenesis) -> Result<Self, Self::Error> {
+        let decode = |hex: &String| {
+            bytes::from_hex(hex).map_err(|e| format!("invalid hex value {hex}: {e:?}"))
+        };
+        let data = |pairs: &BTreeMap<String, String>| {
+            pairs
+                .iter()
+                .map(|(k, v)| Ok((decode(k)?, decode(v)?)))
+                .collect::<Result<BTreeMap<_, _>, String>>()
+        };
+        Ok(Storage {
+            top: data(&raw.top)?,
+            children_default: raw
+                .children_default
+                .iter()
+                .map(|(key, pairs)| {
+                    let child_info = ChildInfo::new_default(&decode(key)?);
+                    Ok((
+                        child_info.storage_key().to_vec(),
+                        StorageChild {
+                            data: data(pairs)?,
+                            child_info,
+                        },
+                    ))
+                })
+                .collect::<Result<_, String>>()?,
+        })
+    }
+}
+
+thread_local! {
+    // genesis files set for the chains of the current thread, by environment variable
+    static GENESIS_FILES: RefCell<BTreeMap<String, PathBuf>> = RefCell::new(BTreeMap::new());
+}
+
+/// Sets the genesis file otherwise read from the environment variable, only for chains built on
+/// the current thread, such as when its network is next reset, or unsets it with `None`. Unlike the
+/// environment variable, this allows tests running concurrently to boot from different files.
+pub(crate) fn set_genesis_file(var: &str, path: Option<PathBuf>) {
+    GENESIS_FILES.with(|files| match path {
+        Some(path) => files.borrow_mut().insert(var.to_string(), path),
+        None => files.borrow_mut().remove(var),
+    });
+}
+
+/// Loads the parachain genesis from the file set for the current thread or specified by the
+/// environment variable, when either is set, checking that it includes runtime code and is for the
+/// parachain.
+pub(crate) fn genesis_from_file(var: &str, para_id: ParaId) -> Option<Storage> {
+    let path = GENESIS_FILES
+        .with(|files| files.borrow().get(var).cloned())
+        .or_else(|| env::var_os(var).map(PathBuf::from))?;
+    let storage = load_genesis(&path)
+        .and_then(|storage| check_parachain_genesis(storage, para_id))
+        .unwrap_or_else(|e| panic!("could not load genesis from {var}: {e}"));
+    tracing::info!(target: "chain_spec", "loaded genesis of parachain {para_id} from {}", path.display());
+    Some(storage)
+}
+
+/// Checks that the genesis includes runtime code and is for the parachain.
+pub(crate) fn check_parachain_genesis(
+    storage: Storage,
+    para_id: ParaId,
+) -> Result<Storage, String> {
+    if !storage.top.contains_key(well_known_keys::CODE) {
+        return Err("genesis does not include runtime code".to_string());
+    }
+    let key = frame_support::storage::storage_prefix(b"ParachainInfo", b"ParachainId");
+    let genesis_para_id = storage
+        .top
+        .get(key.as_slice())
+        .map(|id| ParaId::decode(&mut id.as_slice()))
+        .transpose()
+        .map_err(|e| format!("invalid para id: {e}"))?
+        // parachain info defaults to para id 100 when unset
+        .unwrap_or_else(|| ParaId::new(100));
+    if genesis_para_id != para_id {
+        return Err(format!(
+            "genesis is for para id {genesis_para_id}, expected {para_id}"
+        ));
+    }
+    Ok(storage)
+}
@@ -10,8 +10,16 @@ use xcm_emulator::{
 };
 use xcm_executor::traits::Convert;
 
+pub use chain_spec::export as export_chain_specs;
+pub use scenarios::run_file as run_scenario_file;
+
+mod chain_spec;
 mod parachains;
+mod query;
 mod relay_chain;
+mod scenarios;
+#[cfg(test)]
+mod snapshot;
 #[cfg(test)]
 mod tests;
 
@@ -91,6 +99,26 @@ decl_test_parachains! {
         pallets_extra = {
             Tellor: oracle_consumer_runtime::Tellor,
         }
+    },
+    pub struct SecondOracleConsumerParachain {
+        genesis = parachains::oracle_consumer::second_genesis(),
+        on_init = parachains::oracle_consumer::init(),
+        runtime = {
+            Runtime: oracle_consumer_runtime::Runtime,
+            RuntimeOrigin: oracle_consumer_runtime::RuntimeOrigin,
+            RuntimeCall: oracle_consumer_runtime::RuntimeCall,
+            RuntimeEvent: oracle_consumer_runtime::RuntimeEvent,
+            XcmpMessageHandler: oracle_consumer_runtime::XcmpQueue,
+            DmpMessageHandler: oracle_consumer_runtime::DmpQueue,
+            LocationToAccountId: oracle_consumer_runtime::xcm_config::LocationToAccountId,
+            System: oracle_consumer_runtime::System,
+            Balances: oracle_consumer_runtime::Balances,
+            ParachainSystem: oracle_consumer_runtime::ParachainSystem,
+            ParachainInfo: oracle_consumer_runtime::ParachainInfo,
+        },
+        pallets_extra = {
+            Tellor: oracle_consumer_runtime::Tellor,
+        }
     }
 }
 
@@ -101,6 +129,7 @@ decl_test_networks! {
             AssetReserveParachain,
             EvmParachain,
             OracleConsumerParachain,
+            SecondOracleConsumerParachain,
         ],
     }
 }
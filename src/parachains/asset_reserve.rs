@@ -0,0 +1,83 @@
+use super::*;
+use frame_support::{assert_ok, traits::fungibles::Inspect};
+use integration_tests_common::constants::accounts;
+use sp_runtime::AccountId32;
+use statemine_runtime::{Assets, PolkadotXcm, RuntimeOrigin};
+
+/// The id of the TRB asset on the asset reserve parachain, registered as xcTRB on the evm parachain.
+pub(crate) const TRB_ASSET_ID: u32 = 872;
+const ASSETS_PALLET_INSTANCE: u8 = 50;
+
+lazy_static! {
+    pub(crate) static ref ALICE: AccountId = AssetReserveParachain::account_id_of(accounts::ALICE);
+}
+
+pub(crate) fn create_trb_asset() {
+    assert_ok!(Assets::force_create(
+        RuntimeOrigin::root(),
+        TRB_ASSET_ID.into(),
+        ALICE.clone().into(),
+        true, // sufficient, allowing fees to be paid in trb
+        1_000
+    ));
+    assert_ok!(Assets::set_metadata(
+        RuntimeOrigin::signed(ALICE.clone()),
+        TRB_ASSET_ID.into(),
+        b"Tellor Tribute".to_vec(),
+        b"TRB".to_vec(),
+        18
+    ));
+}
+
+pub(crate) fn mint_trb(who: AccountId32, amount: u128) {
+    assert_ok!(Assets::mint(
+        RuntimeOrigin::signed(ALICE.clone()),
+        TRB_ASSET_ID.into(),
+        who.into(),
+        amount
+    ));
+}
+
+pub(crate) fn trb_balance(who: &AccountId32) -> u128 {
+    <Assets as Inspect<AccountId32>>::balance(TRB_ASSET_ID, who)
+}
+
+/// Reserve transfers trb to an account on the evm parachain, with xcm fees paid in trb.
+pub(crate) fn reserve_transfer_trb(from: AccountId32, to: Address, amount: u128) {
+    let dest = MultiLocation::new(1, X1(Parachain(EvmParachain::para_id().into())));
+    let beneficiary = MultiLocation::new(
+        0,
+        X1(AccountKey20 {
+            network: None,
+            key: to.0,
+        }),
+    );
+    let asset = MultiLocation::new(
+        0,
+        X2(
+            PalletInstance(ASSETS_PALLET_INSTANCE),
+            GeneralIndex(TRB_ASSET_ID.into()),
+        ),
+    );
+    let balance = trb_balance(&from);
+    let reserve = trb_balance(&sovereign_account_of_evm_parachain());
+    assert_ok!(PolkadotXcm::limited_reserve_transfer_assets(
+        RuntimeOrigin::signed(from.clone()),
+        Box::new(VersionedMultiLocation::V3(dest)),
+        Box::new(VersionedMultiLocation::V3(beneficiary)),
+        Box::new(VersionedMultiAssets::V3((asset, amount).into())),
+        0,
+        Unlimited
+    ));
+    // ensure trb moved to sovereign account of evm parachain
+    assert_eq!(trb_balance(&from), balance - amount);
+    assert_eq!(
+        trb_balance(&sovereign_account_of_evm_parachain()),
+        reserve + amount
+    );
+}
+
+pub(crate) fn sovereign_account_of_evm_parachain() -> AccountId32 {
+    use sp_runtime::traits::AccountIdConversion;
+    polkadot_parachain::primitives::Sibling(EvmParachain::para_id()).into_account_truncating()
+}
@@ -0,0 +1,215 @@
+use super::*;
+use keyring::Keyring;
+use sp_runtime::AccountId32;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// A native token or asset balance on one of the chains of the mock network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Asset {
+    /// ROC on the relay chain.
+    Roc,
+    /// KSM on the asset reserve parachain.
+    Ksm,
+    /// TRB on the asset reserve parachain.
+    Trb,
+    /// GLMR on the evm parachain.
+    Glmr,
+    /// xcTRB on the evm parachain.
+    XcTrb,
+    /// The native token of the oracle consumer parachain.
+    Consumer,
+}
+
+impl fmt::Display for Asset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Asset::Roc => "ROC",
+            Asset::Ksm => "KSM",
+            Asset::Trb => "TRB",
+            Asset::Glmr => "GLMR",
+            Asset::XcTrb => "xcTRB",
+            Asset::Consumer => "consumer token",
+        };
+        write!(f, "{name}")
+    }
+}
+
+// an account tracked on the relay chain, asset reserve and oracle consumer parachains, or an address
+// tracked on the evm parachain
+#[derive(Clone, Debug)]
+enum Holder {
+    Account(AccountId32),
+    Address(Address),
+}
+
+type Snapshot = BTreeMap<(String, Asset), u128>;
+
+/// Snapshots the free native and asset balances of the chosen accounts on every chain, allowing the
+/// exact changes from tip claims, dispute fees, slash rewards, staking transfers and xcm fees to be
+/// asserted. Snapshots execute on each chain, so must not be taken within `execute_with`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct BalanceTracker {
+    holders: Vec<(String, Holder)>,
+    assets: Option<Vec<Asset>>,
+    snapshot: Snapshot,
+}
+
+impl BalanceTracker {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracks both the account and the evm address of the actor, labelled with the actor's name.
+    pub(crate) fn actor(self, actor: Keyring) -> Self {
+        self.account(actor.name(), actor.account())
+            .address(actor.name(), actor.address())
+    }
+
+    /// Tracks the account on the relay chain, asset reserve and oracle consumer parachains.
+    pub(crate) fn account(mut self, label: &str, account: AccountId32) -> Self {
+        self.holders
+            .push((label.to_string(), Holder::Account(account)));
+        self
+    }
+
+    /// Tracks the address on the evm parachain.
+    pub(crate) fn address(mut self, label: &str, address: Address) -> Self {
+        self.holders
+            .push((label.to_string(), Holder::Address(address)));
+        self
+    }
+
+    /// Only tracks the assets, such as to ignore gas paid in GLMR when asserting xcTRB transfers.
+    pub(crate) fn only(mut self, assets: &[Asset]) -> Self {
+        self.assets = Some(assets.to_vec());
+        self
+    }
+
+    /// Snapshots the current balances, against which changes are measured.
+    pub(crate) fn start(mut self) -> Self {
+        self.snapshot = self.balances();
+        self
+    }
+
+    /// The changes in balances since the snapshot, excluding any which are unchanged.
+    pub(crate) fn deltas(&self) -> BTreeMap<(String, Asset), i128> {
+        self.balances()
+            .into_iter()
+            .filter_map(|(key, balance)| {
+                let before = self.snapshot.get(&key).copied().unwrap_or_default();
+                (balance != before).then(|| {
+                    let delta = i128::try_from(balance)
+                        .ok()
+                        .zip(i128::try_from(before).ok())
+                        .and_then(|(balance, before)| balance.checked_sub(before))
+                        .unwrap_or_else(|| {
+                            panic!("{} of {} changed from {before} to {balance}", key.1, key.0)
+                        });
+                    (key, delta)
+                })
+            })
+            .collect()
+    }
+
+    /// Asserts that the balances changed by exactly the deltas since the snapshot, with all other
+    /// tracked balances unchanged, listing every difference on failure.
+    pub(crate) fn assert_deltas(&self, expected: &[(&str, Asset, i128)]) {
+        let expected: BTreeMap<_, _> = expected
+            .iter()
+            .filter(|(_, _, delta)| *delta != 0)
+            .map(|(label, asset, delta)| ((label.to_string(), *asset), *delta))
+            .collect();
+        let actual = self.deltas();
+        let differences: Vec<_> = expected
+            .keys()
+            .chain(actual.keys())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .filter_map(|key| {
+                let (expected, actual) = (
+                    expected.get(key).copied().unwrap_or_default(),
+                    actual.get(key).copied().unwrap_or_default(),
+                );
+                (expected != actual).then(|| {
+                    format!(
+                        "  {} of {}: expected {expected:+}, was {actual:+}",
+                        key.1, key.0
+                    )
+                })
+            })
+            .collect();
+        assert!(
+            differences.is_empty(),
+            "balance deltas differ:\n{}",
+            differences.join("\n")
+        );
+    }
+
+    fn balances(&self) -> Snapshot {
+        let mut balances = Snapshot::new();
+        let accounts: Vec<_> = self
+            .holders
+            .iter()
+            .filter_map(|(label, holder)| match holder {
+                Holder::Account(account) => Some((label, account)),
+                Holder::Address(_) => None,
+            })
+            .collect();
+        let addresses: Vec<_> = self
+            .holders
+            .iter()
+            .filter_map(|(label, holder)| match holder {
+                Holder::Address(address) => Some((label, *address)),
+                Holder::Account(_) => None,
+            })
+            .collect();
+        let mut record = |label: &String, asset: Asset, balance: u128| {
+            if self.assets.as_ref().is_none_or(|a| a.contains(&asset)) {
+                balances.insert((label.clone(), asset), balance);
+            }
+        };
+
+        Rococo::execute_with(|| {
+            for (label, account) in &accounts {
+                record(
+                    label,
+                    Asset::Roc,
+                    rococo_runtime::Balances::free_balance(*account),
+                );
+            }
+        });
+        AssetReserveParachain::execute_with(|| {
+            for (label, account) in &accounts {
+                record(
+                    label,
+                    Asset::Ksm,
+                    statemine_runtime::Balances::free_balance(*account),
+                );
+                record(label, Asset::Trb, asset_reserve::trb_balance(account));
+            }
+        });
+        EvmParachain::execute_with(|| {
+            for (label, address) in &addresses {
+                record(
+                    label,
+                    Asset::Glmr,
+                    moonbeam_runtime::Balances::free_balance(account::AccountId20::from(*address)),
+                );
+                record(label, Asset::XcTrb, evm::xctrb_balance(*address));
+            }
+        });
+        OracleConsumerParachain::execute_with(|| {
+            for (label, account) in &accounts {
+                record(
+                    label,
+                    Asset::Consumer,
+                    oracle_consumer_runtime::Balances::free_balance(*account),
+                );
+            }
+        });
+        balances
+    }
+}
@@ -0,0 +1,233 @@
+use super::*;
+use codec::Compact;
+use frame_support::{assert_ok, traits::UnixTime, BoundedVec};
+use oracle_consumer_runtime::{
+    AccountId, Balance, RuntimeEvent, RuntimeOrigin, System, Tellor, Timestamp,
+};
+use reporter::Reporter;
+use sp_core::H256;
+
+/// The time after a report before its tip can be claimed.
+const CLAIM_BUFFER: u64 = 12 * tellor::HOURS;
+
+/// A data feed on the oracle consumer parachain, rewarding the first report within each window of
+/// an interval, or any report changing the value by more than the price threshold. Executes on the
+/// oracle consumer parachain, advancing time on both parachains so that their clocks remain in
+/// sync, so must not be used within `execute_with`.
+#[derive(Clone, Debug)]
+pub(crate) struct DataFeed {
+    pub(crate) query_id: H256,
+    pub(crate) query_data: Vec<u8>,
+    pub(crate) reward: Balance,
+    pub(crate) start_time: u64,
+    pub(crate) interval: u64,
+    pub(crate) window: u64,
+    pub(crate) price_threshold: u16,
+    pub(crate) reward_increase_per_second: Balance,
+    // remaining balance, from which rewards are paid
+    balance: Balance,
+}
+
+impl DataFeed {
+    /// A feed for the query, starting now, without a price threshold or reward increase.
+    pub(crate) fn new(query: &query::Query, reward: Balance, interval: u64, window: u64) -> Self {
+        Self {
+            query_id: query.query_id(),
+            query_data: query.query_data(),
+            reward,
+            start_time: now(),
+            interval,
+            window,
+            price_threshold: 0,
+            reward_increase_per_second: 0,
+            balance: 0,
+        }
+    }
+
+    pub(crate) fn price_threshold(mut self, price_threshold: u16) -> Self {
+        self.price_threshold = price_threshold;
+        self
+    }
+
+    pub(crate) fn reward_increase_per_second(
+        mut self,
+        reward_increase_per_second: Balance,
+    ) -> Self {
+        self.reward_increase_per_second = reward_increase_per_second;
+        self
+    }
+
+    pub(crate) fn id(&self) -> H256 {
+        oracle_consumer::feed_id(
+            self.query_id,
+            self.reward,
+            self.start_time,
+            self.interval,
+            self.window,
+            self.price_threshold,
+            self.reward_increase_per_second,
+        )
+    }
+
+    pub(crate) fn balance(&self) -> Balance {
+        self.balance
+    }
+
+    /// Sets up the feed, funding it with the amount.
+    pub(crate) fn setup(&mut self, creator: AccountId, amount: Balance) {
+        OracleConsumerParachain::execute_with(|| {
+            assert_ok!(Tellor::setup_data_feed(
+                RuntimeOrigin::signed(creator.clone()),
+                self.query_id,
+                self.reward,
+                self.start_time,
+                self.interval,
+                self.window,
+                self.price_threshold,
+                self.reward_increase_per_second,
+                self.query_data.clone().try_into().unwrap(),
+                amount
+            ));
+            System::assert_has_event(
+                tellor::Event::NewDataFeed {
+                    query_id: self.query_id,
+                    feed_id: self.id(),
+                    query_data: self.query_data.clone().try_into().unwrap(),
+                    feed_creator: creator.clone(),
+                }
+                .into(),
+            );
+            if amount > 0 {
+                self.assert_funded_event(creator, amount);
+            }
+        });
+        self.balance += amount;
+    }
+
+    /// Funds the feed with a further amount.
+    pub(crate) fn fund(&mut self, funder: AccountId, amount: Balance) {
+        OracleConsumerParachain::execute_with(|| {
+            assert_ok!(Tellor::fund_feed(
+                RuntimeOrigin::signed(funder.clone()),
+                self.id(),
+                self.query_id,
+                amount
+            ));
+            self.assert_funded_event(funder, amount);
+        });
+        self.balance += amount;
+    }
+
+    /// Advances time on both parachains to the offset from the start of the interval with the given
+    /// index.
+    pub(crate) fn advance_to(&self, interval: u64, offset: u64) {
+        let target = self.start_time + interval * self.interval + offset;
+        let now = now();
+        assert!(target >= now, "cannot go back in time");
+        if target > now {
+            dispute::advance_time(target - now);
+        }
+    }
+
+    /// Submits a value at the offset from the start of the interval with the given index,
+    /// returning the timestamp of the report.
+    pub(crate) fn report(
+        &self,
+        reporter: &mut Reporter,
+        interval: u64,
+        offset: u64,
+        value: &[u8],
+    ) -> tellor::Timestamp {
+        self.advance_to(interval, offset);
+        let (_, timestamp) = OracleConsumerParachain::execute_with(|| {
+            reporter.submit_value_now(&self.query_data, value)
+        });
+        timestamp
+    }
+
+    /// The reward for an eligible report at the timestamp: the reward plus any increase accrued
+    /// since the start of the interval when within the window, limited to the remaining balance.
+    pub(crate) fn reward_at(&self, timestamp: tellor::Timestamp) -> Balance {
+        let since_interval_start = (timestamp - self.start_time) % self.interval;
+        let reward = if since_interval_start < self.window {
+            self.reward + self.reward_increase_per_second * since_interval_start as Balance
+        } else {
+            self.reward
+        };
+        reward.min(self.balance)
+    }
+
+    /// Claims the tips for the reports at the timestamps, advancing time past the claim buffer if
+    /// required, and returns the amount claimed.
+    pub(crate) fn claim(
+        &mut self,
+        reporter: AccountId,
+        timestamps: &[tellor::Timestamp],
+    ) -> Balance {
+        let last = *timestamps.iter().max().expect("timestamps to claim");
+        let now = now();
+        if now <= last + CLAIM_BUFFER {
+            dispute::advance_time(last + CLAIM_BUFFER + 1 - now);
+        }
+
+        let mut amount = 0;
+        for timestamp in timestamps {
+            let reward = self.reward_at(*timestamp);
+            self.balance -= reward;
+            amount += reward;
+        }
+        OracleConsumerParachain::execute_with(|| {
+            assert_ok!(Tellor::claim_tip(
+                RuntimeOrigin::signed(reporter.clone()),
+                self.id(),
+                self.query_id,
+                BoundedVec::truncate_from(timestamps.iter().map(|t| Compact(*t)).collect())
+            ));
+            System::assert_has_event(
+                tellor::Event::TipClaimed {
+                    feed_id: self.id(),
+                    query_id: self.query_id,
+                    amount,
+                    reporter,
+                }
+                .into(),
+            );
+        });
+        amount
+    }
+
+    /// Asserts that claiming the tip for the report at the timestamp fails, as it is ineligible.
+    pub(crate) fn assert_not_claimable(&self, reporter: AccountId, timestamp: tellor::Timestamp) {
+        OracleConsumerParachain::execute_with(|| {
+            assert!(Tellor::claim_tip(
+                RuntimeOrigin::signed(reporter),
+                self.id(),
+                self.query_id,
+                BoundedVec::truncate_from(vec![Compact(timestamp)])
+            )
+            .is_err());
+        });
+    }
+
+    fn assert_funded_event(&self, funder: AccountId, amount: Balance) {
+        let feed_id = self.id();
+        assert!(
+            System::events().iter().any(|r| matches!(
+                &r.event,
+                RuntimeEvent::Tellor(tellor::Event::DataFeedFunded {
+                    feed_id: id,
+                    query_id,
+                    amount: funded,
+                    feed_funder,
+                    ..
+                }) if *id == feed_id && *query_id == self.query_id && *funded == amount && *feed_funder == funder
+            )),
+            "expected DataFeedFunded event"
+        );
+    }
+}
+
+// the current time on the oracle consumer parachain, in seconds
+fn now() -> u64 {
+    OracleConsumerParachain::execute_with(|| <Timestamp as UnixTime>::now().as_secs())
+}
@@ -0,0 +1,252 @@
+use super::*;
+use evm::contracts::{governance, staking};
+use frame_support::{assert_ok, traits::Hooks};
+use oracle_consumer_runtime::{
+    AccountId, Balance, Balances, RuntimeEvent, RuntimeOrigin, System, Tellor,
+};
+use sp_core::H256;
+use sp_runtime::traits::{Hash, Keccak256};
+use tellor::{VoteResult, DAYS, HOURS};
+
+/// The time after the start of a vote round after which votes on the oracle consumer parachain
+/// are sent to the evm parachain.
+pub(crate) const PARACHAIN_VOTING_CUT_OFF: u64 = 11 * HOURS;
+
+/// The id of a dispute of the value reported for the query id at the timestamp on the parachain.
+pub(crate) fn dispute_id(para_id: impl Into<u32>, query_id: H256, timestamp: u64) -> H256 {
+    Keccak256::hash(&ethabi::encode(&[
+        ethabi::Token::Uint(para_id.into().into()),
+        ethabi::Token::FixedBytes(query_id.0.into()),
+        ethabi::Token::Uint(timestamp.into()),
+    ]))
+}
+
+/// A dispute begun on the oracle consumer parachain. Methods execute on the relevant parachains,
+/// so must not be called within `execute_with`.
+#[derive(Clone, Debug)]
+pub(crate) struct Dispute {
+    pub(crate) id: H256,
+    pub(crate) query_id: H256,
+    pub(crate) timestamp: u64,
+    pub(crate) initiator: AccountId,
+    pub(crate) reporter: AccountId,
+    /// The fee paid by the initiator for each vote round.
+    pub(crate) fees: Vec<Balance>,
+}
+
+impl Dispute {
+    /// Begins a dispute of the value reported for the query id at the timestamp, asserting the
+    /// dispute was sent to the evm parachain.
+    pub(crate) fn begin(
+        initiator: AccountId,
+        beneficiary: Address,
+        query_id: H256,
+        timestamp: u64,
+    ) -> Self {
+        let (id, reporter, fee) = OracleConsumerParachain::execute_with(|| {
+            let id = dispute_id(
+                <oracle_consumer_runtime::Runtime as tellor::Config>::ParachainId::get(),
+                query_id,
+                timestamp,
+            );
+            let fee = begin_dispute(&initiator, beneficiary, query_id, timestamp);
+            let reporter = System::events()
+                .into_iter()
+                .rev()
+                .find_map(|r| match r.event {
+                    RuntimeEvent::Tellor(tellor::Event::NewDispute {
+                        dispute_id,
+                        reporter,
+                        ..
+                    }) if dispute_id == id => Some(reporter),
+                    _ => None,
+                })
+                .expect("NewDispute event emitted");
+            (id, reporter, fee)
+        });
+        Self {
+            id,
+            query_id,
+            timestamp,
+            initiator,
+            reporter,
+            fees: vec![fee],
+        }
+    }
+
+    /// The current vote round, starting at 1.
+    pub(crate) fn round(&self) -> u8 {
+        self.fees.len() as u8
+    }
+
+    /// The total fees paid by the initiator across all vote rounds.
+    pub(crate) fn total_fees(&self) -> Balance {
+        self.fees.iter().sum()
+    }
+
+    /// Disputes the value again once the previous round has been tallied, beginning a new round.
+    pub(crate) fn begin_next_round(&mut self, beneficiary: Address) {
+        let fee = OracleConsumerParachain::execute_with(|| {
+            begin_dispute(&self.initiator, beneficiary, self.query_id, self.timestamp)
+        });
+        self.fees.push(fee);
+    }
+
+    /// Casts the votes on the oracle consumer parachain, with `None` voting the query as invalid.
+    pub(crate) fn vote(&self, votes: &[(AccountId, Option<bool>)]) {
+        OracleConsumerParachain::execute_with(|| {
+            for (voter, supports) in votes {
+                assert_ok!(Tellor::vote(
+                    RuntimeOrigin::signed(voter.clone()),
+                    self.id,
+                    *supports
+                ));
+            }
+        });
+    }
+
+    /// Advances time on both parachains past the parachain voting cut-off, sending votes to the
+    /// evm parachain via the `on_initialize` hook.
+    pub(crate) fn advance_to_parachain_voting_cut_off(&self) {
+        advance_time(PARACHAIN_VOTING_CUT_OFF + 1);
+    }
+
+    /// Advances time on both parachains until the voting period of the current round has ended,
+    /// after which votes can be tallied.
+    pub(crate) fn advance_to_end_of_voting_period(&self) {
+        advance_time(DAYS * self.round() as u64 + 1);
+    }
+
+    /// Tallies votes on the evm parachain, asserting the result is reported to the oracle consumer
+    /// parachain.
+    pub(crate) fn tally(&self, result: VoteResult, initiator: Address, reporter: Address) {
+        EvmParachain::execute_with(|| {
+            governance::tally_votes(*evm::ALITH, self.id);
+            governance::assert_vote_tallied_event(self.id, result as u8, initiator, reporter);
+            assert_within_hrmp_limits();
+        });
+        OracleConsumerParachain::execute_with(|| {
+            System::assert_has_event(
+                tellor::Event::VoteTallied {
+                    dispute_id: self.id,
+                    result,
+                    initiator: self.initiator.clone(),
+                    reporter: self.reporter.clone(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    /// Executes the tallied vote on the evm parachain once the tally period has elapsed,
+    /// asserting the result is reported to the oracle consumer parachain.
+    pub(crate) fn execute(&self, result: VoteResult) {
+        advance_time(DAYS * self.round() as u64 + 1);
+        EvmParachain::execute_with(|| {
+            governance::execute_vote(*evm::ALITH, self.id);
+            governance::assert_vote_executed_event(self.id, result as u8);
+            assert_within_hrmp_limits();
+        });
+        OracleConsumerParachain::execute_with(|| {
+            System::assert_has_event(
+                tellor::Event::VoteExecuted {
+                    dispute_id: self.id,
+                    result,
+                }
+                .into(),
+            );
+        });
+    }
+
+    /// Asserts the dispute was begun on the evm parachain for the current round, with the reporter
+    /// slashed and the slash reported back to the oracle consumer parachain on the first round.
+    pub(crate) fn assert_begun_on_evm_parachain(&self, reporter: Address, slash_amount: Balance) {
+        EvmParachain::execute_with(|| {
+            governance::assert_executed(*evm::PALLET_DERIVATIVE_ACCOUNT);
+            governance::assert_new_parachain_dispute_event(
+                OracleConsumerParachain::para_id(),
+                self.query_id.0.to_vec(),
+                self.timestamp,
+                reporter,
+            );
+            assert_eq!(governance::get_vote_rounds(self.id), self.round().into());
+            if self.round() == 1 {
+                staking::assert_parachain_reporter_slashed_event(
+                    OracleConsumerParachain::para_id(),
+                    reporter,
+                    *governance::GOVERNANCE_CONTRACT_ADDRESS,
+                    slash_amount,
+                );
+            }
+        });
+        if self.round() == 1 {
+            OracleConsumerParachain::execute_with(|| {
+                System::assert_has_event(
+                    tellor::Event::SlashReported {
+                        reporter: self.reporter.clone(),
+                        amount: slash_amount.into(),
+                    }
+                    .into(),
+                );
+            });
+        }
+    }
+}
+
+// begins a dispute on the oracle consumer parachain, returning the fee paid
+fn begin_dispute(
+    initiator: &AccountId,
+    beneficiary: Address,
+    query_id: H256,
+    timestamp: u64,
+) -> Balance {
+    let balance = Balances::free_balance(initiator);
+    assert_ok!(Tellor::begin_dispute(
+        RuntimeOrigin::signed(initiator.clone()),
+        query_id,
+        timestamp,
+        Some(beneficiary)
+    ));
+    System::assert_has_event(
+        tellor::Event::NewDisputeSent {
+            para_id: EvmParachain::para_id().into(),
+            contract_address: *governance::GOVERNANCE_CONTRACT_ADDRESS,
+        }
+        .into(),
+    );
+    assert_within_hrmp_limits();
+    balance - Balances::free_balance(initiator)
+}
+
+/// Advances time on both parachains, sending any votes from the oracle consumer parachain via the
+/// `on_initialize` hook.
+pub(crate) fn advance_time(time_in_secs: u64) {
+    EvmParachain::execute_with(|| evm::advance_time(time_in_secs));
+    OracleConsumerParachain::execute_with(|| {
+        oracle_consumer::advance_time(time_in_secs);
+        // send votes via on_initialize hook
+        Tellor::on_initialize(0);
+        assert_within_hrmp_limits();
+    });
+}
+
+/// The difference in seconds between the clocks of the oracle consumer and evm parachains, set when
+/// each is initialised, which advancing time on both must preserve.
+pub(crate) fn clock_skew() -> i64 {
+    use frame_support::traits::UnixTime;
+    let evm = EvmParachain::execute_with(|| <moonbeam_runtime::Timestamp as UnixTime>::now());
+    let consumer = OracleConsumerParachain::execute_with(|| {
+        <oracle_consumer_runtime::Timestamp as UnixTime>::now()
+    });
+    consumer.as_secs() as i64 - evm.as_secs() as i64
+}
+
+// asserts that messages queued for sending by the current parachain fit within hrmp limits
+fn assert_within_hrmp_limits() {
+    for size in outbound_xcmp_page_sizes() {
+        assert!(
+            size <= MAX_HRMP_MESSAGE_SIZE,
+            "xcmp page of {size} bytes exceeds hrmp limit"
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+/// Environment variable pointing at a local Foundry `out/` directory, allowing unreleased contract
+/// builds to be tested without updating the bytecode files within `contracts/`.
+pub(crate) const FOUNDRY_OUT_DIR: &str = "TELLOR_CONTRACTS_OUT";
+
+/// Loads the creation bytecode of the contract, preferring a Foundry artifact within the directory
+/// specified by `TELLOR_CONTRACTS_OUT`, then a Foundry artifact or hex file within `contracts/`.
+pub(crate) fn load(contract: &str) -> Vec<u8> {
+    if let Some(out) = env::var_os(FOUNDRY_OUT_DIR) {
+        // forge build writes artifacts to out/<Contract>.sol/<Contract>.json
+        return from_artifact(
+            &PathBuf::from(out)
+                .join(format!("{contract}.sol"))
+                .join(format!("{contract}.json")),
+        );
+    }
+
+    let contracts = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("contracts");
+    let artifact = contracts.join(format!("{contract}.json"));
+    if artifact.exists() {
+        return from_artifact(&artifact);
+    }
+    from_hex_file(&contracts.join(format!("{contract}.hex")))
+}
+
+/// Reads the `bytecode.object` value from a Foundry JSON artifact.
+pub(crate) fn from_artifact(path: &Path) -> Vec<u8> {
+    let artifact: serde_json::Value = serde_json::from_str(&read(path))
+        .unwrap_or_else(|e| panic!("invalid artifact {}: {e}", path.display()));
+    let bytecode = artifact["bytecode"]["object"]
+        .as_str()
+        .unwrap_or_else(|| panic!("no bytecode.object value in {}", path.display()));
+    decode(bytecode, path)
+}
+
+/// Reads a file containing hex-encoded bytecode.
+pub(crate) fn from_hex_file(path: &Path) -> Vec<u8> {
+    decode(&read(path), path)
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| panic!("could not read {}: {e}", path.display()))
+}
+
+fn decode(bytecode: &str, path: &Path) -> Vec<u8> {
+    sp_core::bytes::from_hex(bytecode.trim())
+        .unwrap_or_else(|e| panic!("invalid bytecode in {}: {e:?}", path.display()))
+}
@@ -0,0 +1,301 @@
+use super::*;
+
+lazy_static! {
+    // events emitted by the xcTRB erc20 precompile
+    pub(crate) static ref EVENTS: Vec<Event> = vec![
+        Event {
+            name: "Transfer".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "from".to_string(),
+                    kind: ParamType::Address,
+                    indexed: true,
+                },
+                EventParam {
+                    name: "to".to_string(),
+                    kind: ParamType::Address,
+                    indexed: true,
+                },
+                EventParam {
+                    name: "value".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "Approval".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "owner".to_string(),
+                    kind: ParamType::Address,
+                    indexed: true,
+                },
+                EventParam {
+                    name: "spender".to_string(),
+                    kind: ParamType::Address,
+                    indexed: true,
+                },
+                EventParam {
+                    name: "value".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+    ];
+}
+
+pub(crate) fn balance_of(owner: Address) -> U256 {
+    #[allow(deprecated)]
+    let function = Function {
+        name: "balanceOf".to_string(),
+        inputs: vec![Param {
+            name: "owner".to_string(),
+            kind: ParamType::Address,
+            internal_type: None,
+        }],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Uint(256),
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: ethabi::StateMutability::View,
+    };
+    let input = function.encode_input(&vec![Token::Address(owner)]).unwrap();
+
+    // query xcTRB precompile
+    let output = Transaction::call(*ALITH, *XCTRB_ADDRESS, input).view();
+    function.decode_output(&output).unwrap()[0]
+        .clone()
+        .into_uint()
+        .unwrap()
+}
+
+pub(crate) fn allowance(owner: Address, spender: Address) -> U256 {
+    #[allow(deprecated)]
+    let function = Function {
+        name: "allowance".to_string(),
+        inputs: vec![
+            Param {
+                name: "owner".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "spender".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+        ],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Uint(256),
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: ethabi::StateMutability::View,
+    };
+    let input = function
+        .encode_input(&vec![Token::Address(owner), Token::Address(spender)])
+        .unwrap();
+
+    // query xcTRB precompile
+    let output = Transaction::call(*ALITH, *XCTRB_ADDRESS, input).view();
+    function.decode_output(&output).unwrap()[0]
+        .clone()
+        .into_uint()
+        .unwrap()
+}
+
+pub(crate) fn approve(owner: Address, spender: Address, amount: u128) {
+    #[allow(deprecated)]
+    let input = Function {
+        name: "approve".to_string(),
+        inputs: vec![
+            Param {
+                name: "spender".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "value".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Bool,
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: Default::default(),
+    }
+    .encode_input(&vec![Token::Address(spender), Token::Uint(amount.into())])
+    .unwrap();
+
+    // call xcTRB precompile
+    let receipt = Transaction::call(owner, *XCTRB_ADDRESS, input).submit();
+    gas::record("xcTRB", "approve", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Returned));
+    assert_eq!(allowance(owner, spender), amount.into());
+    assert_approval_event(owner, spender, amount);
+}
+
+pub(crate) fn transfer(from: Address, to: Address, amount: u128) {
+    #[allow(deprecated)]
+    let input = Function {
+        name: "transfer".to_string(),
+        inputs: vec![
+            Param {
+                name: "to".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "value".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Bool,
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: Default::default(),
+    }
+    .encode_input(&vec![Token::Address(to), Token::Uint(amount.into())])
+    .unwrap();
+
+    // call xcTRB precompile
+    let balances = (balance_of(from), balance_of(to));
+    let receipt = Transaction::call(from, *XCTRB_ADDRESS, input).submit();
+    gas::record("xcTRB", "transfer", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Returned));
+    assert_transferred(from, to, amount, balances);
+    assert_transfer_event(from, to, amount);
+}
+
+pub(crate) fn transfer_from(spender: Address, from: Address, to: Address, amount: u128) {
+    #[allow(deprecated)]
+    let input = Function {
+        name: "transferFrom".to_string(),
+        inputs: vec![
+            Param {
+                name: "from".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "to".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "value".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Bool,
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: Default::default(),
+    }
+    .encode_input(&vec![
+        Token::Address(from),
+        Token::Address(to),
+        Token::Uint(amount.into()),
+    ])
+    .unwrap();
+
+    // call xcTRB precompile
+    let allowance = allowance(from, spender);
+    let balances = (balance_of(from), balance_of(to));
+    let receipt = Transaction::call(spender, *XCTRB_ADDRESS, input).submit();
+    gas::record("xcTRB", "transferFrom", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Returned));
+    assert_eq!(self::allowance(from, spender), allowance - amount);
+    assert_transferred(from, to, amount, balances);
+    assert_transfer_event(from, to, amount);
+}
+
+// asserts that the amount moved from one balance to the other since the balances were taken, with
+// no change for a transfer to self
+fn assert_transferred(
+    from: Address,
+    to: Address,
+    amount: u128,
+    (from_balance, to_balance): (U256, U256),
+) {
+    if from == to {
+        assert_eq!(balance_of(from), from_balance);
+    } else {
+        assert_eq!(balance_of(from), from_balance - amount);
+        assert_eq!(balance_of(to), to_balance + amount);
+    }
+}
+
+fn event(name: &str) -> &'static Event {
+    EVENTS
+        .iter()
+        .find(|e| e.name == name)
+        .expect("event is defined; qed")
+}
+
+pub(crate) fn assert_approval_event(owner: Address, spender: Address, amount: u128) {
+    let event = event("Approval");
+
+    logs::assert_has_log(ethereum::Log {
+        address: *XCTRB_ADDRESS,
+        topics: vec![
+            event.signature(),
+            {
+                let mut topic = [0u8; 32];
+                topic[12..].clone_from_slice(&owner.0);
+                topic
+            }
+            .into(),
+            {
+                let mut topic = [0u8; 32];
+                topic[12..].clone_from_slice(&spender.0);
+                topic
+            }
+            .into(),
+        ],
+        data: encode(&vec![Token::Uint(amount.into())]),
+    });
+}
+
+pub(crate) fn assert_transfer_event(from: Address, to: Address, amount: u128) {
+    let event = event("Transfer");
+
+    logs::assert_has_log(ethereum::Log {
+        address: *XCTRB_ADDRESS,
+        topics: vec![
+            event.signature(),
+            {
+                let mut topic = [0u8; 32];
+                topic[12..].clone_from_slice(&from.0);
+                topic
+            }
+            .into(),
+            {
+                let mut topic = [0u8; 32];
+                topic[12..].clone_from_slice(&to.0);
+                topic
+            }
+            .into(),
+        ],
+        data: encode(&vec![Token::Uint(amount.into())]),
+    });
+}
@@ -0,0 +1,246 @@
+use super::{
+    governance::GOVERNANCE_CONTRACT_ADDRESS, registry::REGISTRY_CONTRACT_ADDRESS,
+    staking::STAKING_CONTRACT_ADDRESS,
+};
+use crate::parachains::evm::transaction::cumulative_gas_used;
+use ethabi::ParamType;
+use lazy_static::lazy_static;
+use moonbeam_runtime::Runtime;
+use sp_runtime::app_crypto::sp_core::U256;
+use std::{
+    collections::BTreeMap,
+    env,
+    fs::{self, OpenOptions},
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+use tellor::Address;
+
+/// Environment variable selecting the gas snapshot mode: `write` to (re)generate the snapshot file
+/// or `check` to compare the gas used by each contract call against it.
+pub(crate) const GAS_SNAPSHOT: &str = "GAS_SNAPSHOT";
+/// Environment variable overriding the percentage by which gas used may exceed the snapshot.
+pub(crate) const GAS_SNAPSHOT_TOLERANCE: &str = "GAS_SNAPSHOT_TOLERANCE";
+const DEFAULT_TOLERANCE: u64 = 1;
+// the time to wait for another test process to finish writing the snapshot file
+const LOCK_TIMEOUT: Duration = Duration::from_secs(30);
+
+lazy_static! {
+    static ref SNAPSHOT: BTreeMap<String, u64> = parse(&read(&path()));
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Mode {
+    Write,
+    Check,
+}
+
+impl Mode {
+    fn from_env() -> Option<Self> {
+        match env::var(GAS_SNAPSHOT).ok()?.as_str() {
+            "write" => Some(Mode::Write),
+            "check" => Some(Mode::Check),
+            mode => panic!("invalid {GAS_SNAPSHOT} value {mode}, expected 'write' or 'check'"),
+        }
+    }
+}
+
+/// Records the gas used by a call to a contract function, merging it into or checking it against the
+/// `.gas-snapshot` file depending on the `GAS_SNAPSHOT` environment variable. The maximum gas used
+/// per function is kept, as the fixed XCM weights paid by the tellor pallet must cover the worst case,
+/// so the file must be deleted before regenerating to reflect any reduction in gas used.
+pub(crate) fn record(contract: &str, function: &str, used_gas: U256) {
+    let key = format!("{contract}:{function}");
+    let used_gas = used_gas.as_u64();
+    match Mode::from_env() {
+        None => {}
+        Some(Mode::Write) => {
+            // tests may run in separate processes (e.g. cargo nextest) or only in part, so the
+            // file is merged with rather than overwritten, under a lock shared between processes
+            let _lock = Lock::acquire(path().with_extension("lock"));
+            let mut snapshot = parse(&fs::read_to_string(path()).unwrap_or_default());
+            if merge(&mut snapshot, key, used_gas) {
+                // written via a temporary file, so that the snapshot is never partially written
+                let temp = path().with_extension(format!("{}.tmp", std::process::id()));
+                fs::write(&temp, format(&snapshot)).expect("could not write gas snapshot");
+                fs::rename(&temp, path()).expect("could not write gas snapshot");
+            }
+        }
+        Some(Mode::Check) => {
+            if let Err(e) = check(&SNAPSHOT, &key, used_gas, tolerance()) {
+                panic!("{e}")
+            }
+        }
+    }
+}
+
+/// Records the gas used by each call made by the caller (e.g. the pallet derivative account) via XCM
+/// `Transact` within the current block, as these calls are paid for by the tellor pallet with fixed
+/// weights. Calls are named by the contract and function called, or the function selector when
+/// unknown. Must be executed on the evm parachain.
+pub(crate) fn record_transacted(caller: Address) {
+    // receipts report the cumulative gas used within the current block
+    let mut cumulative = U256::zero();
+    for (transaction, status, receipt) in pallet_ethereum::Pending::<Runtime>::get() {
+        let used_gas = cumulative_gas_used(&receipt) - cumulative;
+        cumulative = cumulative_gas_used(&receipt);
+        let (Some(to), true) = (status.to, status.from == caller) else {
+            continue;
+        };
+        let input = match &transaction {
+            ethereum::TransactionV2::Legacy(t) => &t.input,
+            ethereum::TransactionV2::EIP2930(t) => &t.input,
+            ethereum::TransactionV2::EIP1559(t) => &t.input,
+        };
+        if let Some((contract, function)) = transacted_function(to, input) {
+            record(contract, &function, used_gas);
+        }
+    }
+}
+
+// the contract and function called via xcm transact by the tellor pallet
+fn transacted_function(to: Address, input: &[u8]) -> Option<(&'static str, String)> {
+    use ParamType as P;
+    let (contract, functions) = match to {
+        to if to == *REGISTRY_CONTRACT_ADDRESS => (
+            "ParachainRegistry",
+            vec![("register", vec![P::Uint(32), P::Uint(8), P::Uint(256)])],
+        ),
+        to if to == *GOVERNANCE_CONTRACT_ADDRESS => (
+            "ParachainGovernance",
+            vec![
+                (
+                    "beginParachainDispute",
+                    vec![
+                        P::FixedBytes(32),
+                        P::Uint(256),
+                        P::Bytes,
+                        P::Address,
+                        P::Address,
+                        P::Uint(256),
+                        P::Uint(256),
+                    ],
+                ),
+                (
+                    "voteParachain",
+                    [vec![P::FixedBytes(32)], vec![P::Uint(256); 6]].concat(),
+                ),
+            ],
+        ),
+        to if to == *STAKING_CONTRACT_ADDRESS => ("ParachainStaking", vec![]),
+        _ => return None,
+    };
+    let selector = input.get(..4)?;
+    let function = functions
+        .into_iter()
+        .find(|(name, params)| ethabi::short_signature(name, params) == selector)
+        .map(|(name, _)| name.to_string())
+        .unwrap_or_else(|| sp_core::bytes::to_hex(selector, false));
+    Some((contract, function))
+}
+
+/// Merges the gas used into the snapshot, keeping the maximum per key and returning whether the
+/// snapshot changed.
+pub(crate) fn merge(snapshot: &mut BTreeMap<String, u64>, key: String, used_gas: u64) -> bool {
+    let entry = snapshot.entry(key).or_default();
+    let changed = used_gas > *entry;
+    *entry = (*entry).max(used_gas);
+    changed
+}
+
+/// Checks the gas used against the snapshot, failing when it exceeds the snapshot value by more
+/// than the tolerance (as a percentage).
+pub(crate) fn check(
+    snapshot: &BTreeMap<String, u64>,
+    key: &str,
+    used_gas: u64,
+    tolerance: u64,
+) -> Result<(), String> {
+    let Some(expected) = snapshot.get(key) else {
+        return Err(format!(
+            "no gas snapshot for {key}, regenerate using {GAS_SNAPSHOT}=write cargo test"
+        ));
+    };
+    let threshold = expected + expected * tolerance / 100;
+    if used_gas > threshold {
+        return Err(format!(
+            "{key} used {used_gas} gas, exceeding snapshot of {expected} by more than {tolerance}%"
+        ));
+    }
+    Ok(())
+}
+
+/// Parses snapshot lines in the form `Contract:function (gas: 123)`.
+pub(crate) fn parse(snapshot: &str) -> BTreeMap<String, u64> {
+    snapshot
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            line.trim()
+                .strip_suffix(')')
+                .and_then(|line| line.split_once(" (gas: "))
+                .and_then(|(key, gas)| Some((key.to_string(), gas.parse().ok()?)))
+                .unwrap_or_else(|| panic!("invalid gas snapshot line: {line}"))
+        })
+        .collect()
+}
+
+pub(crate) fn format(snapshot: &BTreeMap<String, u64>) -> String {
+    snapshot
+        .iter()
+        .map(|(key, gas)| format!("{key} (gas: {gas})\n"))
+        .collect()
+}
+
+// a lock file created exclusively, shared between test processes and removed when dropped
+struct Lock(PathBuf);
+
+impl Lock {
+    fn acquire(path: PathBuf) -> Self {
+        let start = Instant::now();
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Lock(path),
+                Err(e)
+                    if e.kind() == ErrorKind::AlreadyExists && start.elapsed() < LOCK_TIMEOUT =>
+                {
+                    thread::sleep(Duration::from_millis(10))
+                }
+                Err(e) => panic!(
+                    "could not lock {}: {e}, remove it if no tests are running",
+                    path.display()
+                ),
+            }
+        }
+    }
+}
+
+impl Drop for Lock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".gas-snapshot")
+}
+
+fn read(path: &Path) -> String {
+    fs::read_to_string(path).unwrap_or_else(|e| {
+        panic!(
+            "could not read {}: {e}, generate using {GAS_SNAPSHOT}=write cargo test",
+            path.display()
+        )
+    })
+}
+
+fn tolerance() -> u64 {
+    env::var(GAS_SNAPSHOT_TOLERANCE)
+        .map(|t| {
+            t.parse()
+                .unwrap_or_else(|_| panic!("invalid {GAS_SNAPSHOT_TOLERANCE} value {t}"))
+        })
+        .unwrap_or(DEFAULT_TOLERANCE)
+}
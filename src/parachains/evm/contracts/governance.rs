@@ -3,15 +3,10 @@ use super::*;
 lazy_static! {
     pub(crate) static ref GOVERNANCE_CONTRACT_ADDRESS: Address =
         address_of("3ed62137c5db927cb137c26455969116bf0c23cb");
+    // bytecode:object value from ParachainGovernance.json, generated by forge build
+    static ref CONTRACT_BYTECODE: Vec<u8> = bytecode::load("ParachainGovernance");
 }
 
-// bytecode:object value from ParachainGovernance.json, generated by forge build
-const CONTRACT_BYTECODE: [u8; 10869] = {
-    with_builtin!(let $hex_str = include_str_from_root!("contracts/ParachainGovernance.hex") in {
-        hex!($hex_str)
-    })
-};
-
 pub(crate) fn deploy(registry: Address, team_multisig: Address) {
     // use contract bytecode + constructor parameters
     let mut init = CONTRACT_BYTECODE.to_vec();
@@ -24,23 +19,10 @@ pub(crate) fn deploy(registry: Address, team_multisig: Address) {
     );
 
     // create parachain governance contract
-    assert_ok!(EVM::create(
-        RuntimeOrigin::root(),
-        *ALITH,
-        init,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_last_event(
-        pallet_evm::Event::Created {
-            address: *GOVERNANCE_CONTRACT_ADDRESS,
-        }
-        .into(),
-    );
+    let receipt = Transaction::create(*ALITH, init).submit();
+    gas::record("ParachainGovernance", "constructor", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Returned));
+    assert_eq!(receipt.to, *GOVERNANCE_CONTRACT_ADDRESS);
 }
 
 pub(crate) fn init(staking: Address) {
@@ -60,24 +42,292 @@ pub(crate) fn init(staking: Address) {
     .unwrap();
 
     // init parachain governance contract
-    assert_ok!(EVM::call(
-        RuntimeOrigin::root(),
-        *ALITH,
+    let receipt = Transaction::call(*ALITH, *GOVERNANCE_CONTRACT_ADDRESS, input).submit();
+    gas::record("ParachainGovernance", "init", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
+}
+
+pub(crate) fn begin_parachain_dispute(
+    query_id: H256,
+    timestamp: u64,
+    value: Vec<u8>,
+    disputed_reporter: Address,
+    dispute_initiator: Address,
+    dispute_fee: u128,
+    slash_amount: u128,
+) {
+    #[allow(deprecated)]
+    let input = Function {
+        name: "beginParachainDispute".to_string(),
+        inputs: vec![
+            Param {
+                name: "_queryId".to_string(),
+                kind: ParamType::FixedBytes(32),
+                internal_type: None,
+            },
+            Param {
+                name: "_timestamp".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+            Param {
+                name: "_value".to_string(),
+                kind: ParamType::Bytes,
+                internal_type: None,
+            },
+            Param {
+                name: "_disputedReporter".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "_disputeInitiator".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+            Param {
+                name: "_disputeFee".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+            Param {
+                name: "_slashAmount".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![],
+        constant: None,
+        state_mutability: Default::default(),
+    }
+    .encode_input(&vec![
+        Token::FixedBytes(query_id.0.to_vec()),
+        Token::Uint(timestamp.into()),
+        Token::Bytes(value),
+        Token::Address(disputed_reporter),
+        Token::Address(dispute_initiator),
+        Token::Uint(dispute_fee.into()),
+        Token::Uint(slash_amount.into()),
+    ])
+    .unwrap();
+
+    // call parachain governance contract directly as the parachain owner (pallet derivative
+    // account), which is otherwise only possible via xcm transact from the tellor pallet
+    let receipt = Transaction::call(
+        *PALLET_DERIVATIVE_ACCOUNT,
         *GOVERNANCE_CONTRACT_ADDRESS,
         input,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_has_event(
-        pallet_evm::Event::Executed {
-            address: *GOVERNANCE_CONTRACT_ADDRESS,
-        }
-        .into(),
+    )
+    .dispatch();
+    gas::record(
+        "ParachainGovernance",
+        "beginParachainDispute",
+        receipt.used_gas,
     );
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
+}
+
+pub(crate) fn vote(source: Address, dispute_id: H256, supports: bool, invalid_query: bool) {
+    #[allow(deprecated)]
+    let input = Function {
+        name: "vote".to_string(),
+        inputs: vec![
+            Param {
+                name: "_disputeId".to_string(),
+                kind: ParamType::FixedBytes(32),
+                internal_type: None,
+            },
+            Param {
+                name: "_supports".to_string(),
+                kind: ParamType::Bool,
+                internal_type: None,
+            },
+            Param {
+                name: "_invalidQuery".to_string(),
+                kind: ParamType::Bool,
+                internal_type: None,
+            },
+        ],
+        outputs: vec![],
+        constant: None,
+        state_mutability: Default::default(),
+    }
+    .encode_input(&vec![
+        Token::FixedBytes(dispute_id.0.to_vec()),
+        Token::Bool(supports),
+        Token::Bool(invalid_query),
+    ])
+    .unwrap();
+
+    // call parachain governance contract, as a token holder or the team multisig
+    let receipt = Transaction::call(source, *GOVERNANCE_CONTRACT_ADDRESS, input).submit();
+    gas::record("ParachainGovernance", "vote", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
+}
+
+pub(crate) fn get_vote_rounds(dispute_id: H256) -> U256 {
+    #[allow(deprecated)]
+    let function = Function {
+        name: "getVoteRounds".to_string(),
+        inputs: vec![Param {
+            name: "_disputeId".to_string(),
+            kind: ParamType::FixedBytes(32),
+            internal_type: None,
+        }],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Uint(256),
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: ethabi::StateMutability::View,
+    };
+    let input = function
+        .encode_input(&vec![Token::FixedBytes(dispute_id.0.to_vec())])
+        .unwrap();
+
+    // query parachain governance contract
+    let output = Transaction::call(*ALITH, *GOVERNANCE_CONTRACT_ADDRESS, input).view();
+    function.decode_output(&output).unwrap()[0]
+        .clone()
+        .into_uint()
+        .unwrap()
+}
+
+/// The votes for, against and invalidating a query of one voter group, as tallied by the parachain
+/// governance contract.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct Tally {
+    pub(crate) does_support: U256,
+    pub(crate) against: U256,
+    pub(crate) invalid_query: U256,
+}
+
+/// The tallies of each voter group for a vote round of a dispute.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct VoteTallies {
+    pub(crate) token_holders: Tally,
+    pub(crate) users: Tally,
+    pub(crate) reporters: Tally,
+    pub(crate) team_multisig: Tally,
+}
+
+/// The tallies of a vote round of the dispute, which are zero for a round which has not begun.
+pub(crate) fn get_vote_tallies(dispute_id: H256, vote_round: u8) -> VoteTallies {
+    #[allow(deprecated)]
+    let function = Function {
+        name: "getVoteInfo".to_string(),
+        inputs: vec![
+            Param {
+                name: "_disputeId".to_string(),
+                kind: ParamType::FixedBytes(32),
+                internal_type: None,
+            },
+            Param {
+                name: "_voteRound".to_string(),
+                kind: ParamType::Uint(8),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![
+            Param {
+                name: "".to_string(),
+                kind: ParamType::FixedBytes(32),
+                internal_type: None,
+            },
+            Param {
+                name: "".to_string(),
+                kind: ParamType::FixedArray(Box::new(ParamType::Uint(256)), 17),
+                internal_type: None,
+            },
+            Param {
+                name: "".to_string(),
+                kind: ParamType::Bool,
+                internal_type: None,
+            },
+            Param {
+                name: "".to_string(),
+                kind: ParamType::Uint(8),
+                internal_type: None,
+            },
+            Param {
+                name: "".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+        ],
+        constant: None,
+        state_mutability: ethabi::StateMutability::View,
+    };
+    let input = function
+        .encode_input(&vec![
+            Token::FixedBytes(dispute_id.0.to_vec()),
+            Token::Uint(vote_round.into()),
+        ])
+        .unwrap();
+
+    // query parachain governance contract
+    let output = Transaction::call(*ALITH, *GOVERNANCE_CONTRACT_ADDRESS, input).view();
+    // vote round, start date, block number, fee and tally date, followed by the tallies of each
+    // voter group
+    let info: Vec<U256> = function.decode_output(&output).unwrap()[1]
+        .clone()
+        .into_fixed_array()
+        .unwrap()
+        .into_iter()
+        .map(|value| value.into_uint().unwrap())
+        .collect();
+    let tally = |group: usize| Tally {
+        does_support: info[5 + group * 3],
+        against: info[6 + group * 3],
+        invalid_query: info[7 + group * 3],
+    };
+    VoteTallies {
+        token_holders: tally(0),
+        users: tally(1),
+        reporters: tally(2),
+        team_multisig: tally(3),
+    }
+}
+
+/// Whether the voter voted in the latest vote round of the dispute.
+pub(crate) fn did_vote(dispute_id: H256, voter: Address) -> bool {
+    #[allow(deprecated)]
+    let function = Function {
+        name: "didVote".to_string(),
+        inputs: vec![
+            Param {
+                name: "_disputeId".to_string(),
+                kind: ParamType::FixedBytes(32),
+                internal_type: None,
+            },
+            Param {
+                name: "_voter".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
+            },
+        ],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Bool,
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: ethabi::StateMutability::View,
+    };
+    let input = function
+        .encode_input(&vec![
+            Token::FixedBytes(dispute_id.0.to_vec()),
+            Token::Address(voter),
+        ])
+        .unwrap();
+
+    // query parachain governance contract
+    let output = Transaction::call(*ALITH, *GOVERNANCE_CONTRACT_ADDRESS, input).view();
+    function.decode_output(&output).unwrap()[0]
+        .clone()
+        .into_bool()
+        .unwrap()
 }
 
 pub(crate) fn tally_votes(source: Address, dispute_id: H256) {
@@ -97,24 +347,9 @@ pub(crate) fn tally_votes(source: Address, dispute_id: H256) {
     .unwrap();
 
     // call parachain governance contract
-    assert_ok!(EVM::call(
-        RuntimeOrigin::root(),
-        source,
-        *GOVERNANCE_CONTRACT_ADDRESS,
-        input,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_has_event(
-        pallet_evm::Event::Executed {
-            address: *GOVERNANCE_CONTRACT_ADDRESS,
-        }
-        .into(),
-    );
+    let receipt = Transaction::call(source, *GOVERNANCE_CONTRACT_ADDRESS, input).submit();
+    gas::record("ParachainGovernance", "tallyVotes", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
 }
 
 pub(crate) fn execute_vote(source: Address, dispute_id: H256) {
@@ -134,27 +369,160 @@ pub(crate) fn execute_vote(source: Address, dispute_id: H256) {
     .unwrap();
 
     // call parachain governance contract
-    assert_ok!(EVM::call(
-        RuntimeOrigin::root(),
-        source,
-        *GOVERNANCE_CONTRACT_ADDRESS,
-        input,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_has_event(
-        pallet_evm::Event::Executed {
-            address: *GOVERNANCE_CONTRACT_ADDRESS,
-        }
-        .into(),
-    );
+    let receipt = Transaction::call(source, *GOVERNANCE_CONTRACT_ADDRESS, input).submit();
+    gas::record("ParachainGovernance", "executeVote", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
+}
+
+lazy_static! {
+    pub(crate) static ref EVENTS: Vec<Event> = vec![
+        Event {
+            name: "NewParachainDispute".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_paraId".to_string(),
+                    kind: ParamType::Uint(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_queryId".to_string(),
+                    kind: ParamType::FixedBytes(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_timestamp".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_reporter".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "ParachainVoted".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_disputeId".to_string(),
+                    kind: ParamType::FixedBytes(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_totalTipsFor".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_totalTipsAgainst".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_totalTipsInvalid".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_totalReportsFor".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_totalReportsAgainst".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_totalReportsInvalid".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "VoteTallied".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_disputeId".to_string(),
+                    kind: ParamType::FixedBytes(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_result".to_string(),
+                    kind: ParamType::Uint(8),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_initiator".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_reporter".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "VoteExecuted".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_disputeId".to_string(),
+                    kind: ParamType::FixedBytes(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_result".to_string(),
+                    kind: ParamType::Uint(8),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "Voted".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_disputeId".to_string(),
+                    kind: ParamType::FixedBytes(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_supports".to_string(),
+                    kind: ParamType::Bool,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_voter".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_invalidQuery".to_string(),
+                    kind: ParamType::Bool,
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+    ];
+}
+
+fn event(name: &str) -> &'static Event {
+    EVENTS
+        .iter()
+        .find(|e| e.name == name)
+        .expect("event is defined; qed")
 }
 
 pub(crate) fn assert_executed(caller: Address) {
+    gas::record_transacted(caller);
     assert!(System::events().iter().any(|r| {
         match &r.event {
             RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed {
@@ -179,48 +547,18 @@ pub(crate) fn assert_new_parachain_dispute_event(
     timestamp: u64,
     reporter: Address,
 ) {
-    let event = Event {
-        name: "NewParachainDispute".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_paraId".to_string(),
-                kind: ParamType::Uint(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_queryId".to_string(),
-                kind: ParamType::FixedBytes(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_timestamp".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-            EventParam {
-                name: "_reporter".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("NewParachainDispute");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *GOVERNANCE_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::Uint(para_id.into().into()),
-                    Token::FixedBytes(query_id),
-                    Token::Uint(timestamp.into()),
-                    Token::Address(reporter),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *GOVERNANCE_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::Uint(para_id.into().into()),
+            Token::FixedBytes(query_id),
+            Token::Uint(timestamp.into()),
+            Token::Address(reporter),
+        ]),
+    });
 }
 
 pub(crate) fn assert_parachain_voted_event(
@@ -232,66 +570,21 @@ pub(crate) fn assert_parachain_voted_event(
     total_reports_against: impl Into<U256>,
     total_reports_invalid: impl Into<U256>,
 ) {
-    let event = Event {
-        name: "ParachainVoted".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_disputeId".to_string(),
-                kind: ParamType::FixedBytes(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_totalTipsFor".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-            EventParam {
-                name: "_totalTipsAgainst".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-            EventParam {
-                name: "_totalTipsInvalid".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-            EventParam {
-                name: "_totalReportsFor".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-            EventParam {
-                name: "_totalReportsAgainst".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-            EventParam {
-                name: "_totalReportsInvalid".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("ParachainVoted");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *GOVERNANCE_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::FixedBytes(dispute_id.0.to_vec()),
-                    Token::Uint(total_tips_for.into()),
-                    Token::Uint(total_tips_against.into()),
-                    Token::Uint(total_tips_invalid.into()),
-                    Token::Uint(total_reports_for.into()),
-                    Token::Uint(total_reports_against.into()),
-                    Token::Uint(total_reports_invalid.into()),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *GOVERNANCE_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::FixedBytes(dispute_id.0.to_vec()),
+            Token::Uint(total_tips_for.into()),
+            Token::Uint(total_tips_against.into()),
+            Token::Uint(total_tips_invalid.into()),
+            Token::Uint(total_reports_for.into()),
+            Token::Uint(total_reports_against.into()),
+            Token::Uint(total_reports_invalid.into()),
+        ]),
+    });
 }
 
 pub(crate) fn assert_vote_tallied_event(
@@ -300,79 +593,49 @@ pub(crate) fn assert_vote_tallied_event(
     initiator: Address,
     reporter: Address,
 ) {
-    let event = Event {
-        name: "VoteTallied".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_disputeId".to_string(),
-                kind: ParamType::FixedBytes(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_result".to_string(),
-                kind: ParamType::Uint(8),
-                indexed: false,
-            },
-            EventParam {
-                name: "_initiator".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-            EventParam {
-                name: "_reporter".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("VoteTallied");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *GOVERNANCE_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::FixedBytes(dispute_id.0.to_vec()),
-                    Token::Uint((vote_result as u8).into()),
-                    Token::Address(initiator),
-                    Token::Address(reporter),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *GOVERNANCE_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::FixedBytes(dispute_id.0.to_vec()),
+            Token::Uint((vote_result as u8).into()),
+            Token::Address(initiator),
+            Token::Address(reporter),
+        ]),
+    });
 }
 
 pub(crate) fn assert_vote_executed_event(dispute_id: H256, vote_result: u8) {
-    let event = Event {
-        name: "VoteExecuted".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_disputeId".to_string(),
-                kind: ParamType::FixedBytes(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_result".to_string(),
-                kind: ParamType::Uint(8),
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("VoteExecuted");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *GOVERNANCE_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::FixedBytes(dispute_id.0.to_vec()),
-                    Token::Uint((vote_result as u8).into()),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *GOVERNANCE_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::FixedBytes(dispute_id.0.to_vec()),
+            Token::Uint((vote_result as u8).into()),
+        ]),
+    });
+}
+
+pub(crate) fn assert_voted_event(
+    dispute_id: H256,
+    supports: bool,
+    voter: Address,
+    invalid_query: bool,
+) {
+    let event = event("Voted");
+
+    logs::assert_has_log(ethereum::Log {
+        address: *GOVERNANCE_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::FixedBytes(dispute_id.0.to_vec()),
+            Token::Bool(supports),
+            Token::Address(voter),
+            Token::Bool(invalid_query),
+        ]),
+    });
 }
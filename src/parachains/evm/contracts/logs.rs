@@ -0,0 +1,137 @@
+use super::*;
+use crate::parachains::keyring::Keyring;
+use ethabi::RawLog;
+use std::fmt;
+
+/// A log emitted by a known contract, decoded using the contract ABI.
+#[derive(Debug, PartialEq)]
+pub(crate) struct DecodedLog {
+    pub(crate) contract: &'static str,
+    pub(crate) address: Address,
+    pub(crate) name: String,
+    pub(crate) params: Vec<(String, Token)>,
+}
+
+impl fmt::Display for DecodedLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}({:?}).{}(", self.contract, self.address, self.name)?;
+        for (i, (name, value)) in self.params.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}: {}", display(value))?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl DecodedLog {
+    /// The value of the named parameter.
+    pub(crate) fn param(&self, name: &str) -> Option<&Token> {
+        self.params
+            .iter()
+            .find_map(|(n, value)| (n == name).then_some(value))
+    }
+}
+
+/// Decodes a log emitted by one of the Tellor contracts or the xcTRB precompile, returning `None`
+/// when the emitting contract or event is unknown.
+pub(crate) fn decode(log: &ethereum::Log) -> Option<DecodedLog> {
+    let (contract, events): (_, &Vec<Event>) = match log.address {
+        address if address == *governance::GOVERNANCE_CONTRACT_ADDRESS => {
+            ("ParachainGovernance", &governance::EVENTS)
+        }
+        address if address == *registry::REGISTRY_CONTRACT_ADDRESS => {
+            ("ParachainRegistry", &registry::EVENTS)
+        }
+        address if address == *staking::STAKING_CONTRACT_ADDRESS => {
+            ("ParachainStaking", &staking::EVENTS)
+        }
+        address if address == *XCTRB_ADDRESS => ("xcTRB", &erc20::EVENTS),
+        _ => return None,
+    };
+    let signature = log.topics.first()?;
+    let event = events.iter().find(|e| e.signature() == *signature)?;
+    let decoded = event
+        .parse_log(RawLog {
+            topics: log.topics.clone(),
+            data: log.data.clone(),
+        })
+        .ok()?;
+    Some(DecodedLog {
+        contract,
+        address: log.address,
+        name: event.name.clone(),
+        params: decoded
+            .params
+            .into_iter()
+            .map(|p| (p.name, p.value))
+            .collect(),
+    })
+}
+
+/// Returns all logs emitted by known contracts within the current block, decoded using their ABIs.
+pub(crate) fn decoded() -> Vec<DecodedLog> {
+    System::events()
+        .into_iter()
+        .filter_map(|r| match r.event {
+            RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => decode(&log),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns all logs emitted within the current block, formatted using the contract ABIs where known.
+pub(crate) fn formatted() -> Vec<String> {
+    System::events()
+        .into_iter()
+        .filter_map(|r| match r.event {
+            RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => Some(describe(&log)),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Asserts that the log was emitted, printing all decoded logs on failure.
+pub(crate) fn assert_has_log(log: ethereum::Log) {
+    let emitted = System::events().iter().any(
+        |r| matches!(&r.event, RuntimeEvent::EVM(pallet_evm::Event::Log { log: l }) if *l == log),
+    );
+    if !emitted {
+        panic!(
+            "expected log not emitted: {}\nemitted logs:\n{}",
+            describe(&log),
+            formatted()
+                .iter()
+                .map(|l| format!("  {l}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
+
+// labels the addresses of known actors, so that logs are readable when printed
+fn describe(log: &ethereum::Log) -> String {
+    Keyring::annotate(
+        &decode(log)
+            .map(|l| l.to_string())
+            .unwrap_or_else(|| format!("{log:?}")),
+    )
+}
+
+fn display(token: &Token) -> String {
+    match token {
+        Token::Address(address) => format!("{address:?}"),
+        Token::FixedBytes(bytes) | Token::Bytes(bytes) => sp_core::bytes::to_hex(bytes, false),
+        Token::Uint(value) => value.to_string(),
+        Token::Array(tokens) | Token::FixedArray(tokens) => format!(
+            "[{}]",
+            tokens.iter().map(display).collect::<Vec<_>>().join(", ")
+        ),
+        Token::Tuple(tokens) => format!(
+            "({})",
+            tokens.iter().map(display).collect::<Vec<_>>().join(", ")
+        ),
+        _ => token.to_string(),
+    }
+}
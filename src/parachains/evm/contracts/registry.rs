@@ -3,37 +3,289 @@ use super::*;
 lazy_static! {
     pub(crate) static ref REGISTRY_CONTRACT_ADDRESS: Address =
         address_of("c01ee7f10ea4af4673cfff62710e1d7792aba8f3");
+    // bytecode:object value from ParachainRegistry.json, generated by forge build
+    static ref CONTRACT_BYTECODE: Vec<u8> = bytecode::load("ParachainRegistry");
 }
 
-// bytecode:object value from ParachainRegistry.json, generated by forge build
-const CONTRACT_BYTECODE: [u8; 4342] = {
-    with_builtin!(let $hex_str = include_str_from_root!("contracts/ParachainRegistry.hex") in {
-        hex!($hex_str)
-    })
-};
-
 pub(crate) fn deploy() {
     // create parachain registry contract
-    assert_ok!(EVM::create(
-        RuntimeOrigin::root(),
-        *ALITH,
-        CONTRACT_BYTECODE.into(),
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_last_event(
-        pallet_evm::Event::Created {
-            address: *REGISTRY_CONTRACT_ADDRESS,
-        }
-        .into(),
+    let receipt = Transaction::create(*ALITH, CONTRACT_BYTECODE.clone()).submit();
+    gas::record("ParachainRegistry", "constructor", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Returned));
+    assert_eq!(receipt.to, *REGISTRY_CONTRACT_ADDRESS);
+}
+
+/// A parachain registration, as stored by the parachain registry contract.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Parachain {
+    pub(crate) id: u32,
+    pub(crate) owner: Address,
+    pub(crate) pallet_instance: u8,
+    pub(crate) stake_amount: U256,
+    pub(crate) weights: tellor::Weights,
+}
+
+pub(crate) fn register(
+    source: Address,
+    para_id: impl Into<u32>,
+    pallet_instance: u8,
+    stake_amount: u128,
+    owner: &MultiLocation,
+    weights: &tellor::Weights,
+) {
+    let receipt = try_register(
+        source,
+        para_id,
+        pallet_instance,
+        stake_amount,
+        owner,
+        weights,
     );
+    gas::record("ParachainRegistry", "register", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
+}
+
+/// Registers a parachain without asserting the outcome, allowing registrations from accounts other
+/// than the parachain's pallet derivative account to be tested.
+pub(crate) fn try_register(
+    source: Address,
+    para_id: impl Into<u32>,
+    pallet_instance: u8,
+    stake_amount: u128,
+    owner: &MultiLocation,
+    weights: &tellor::Weights,
+) -> Receipt {
+    let input = register_input(para_id, pallet_instance, stake_amount, owner, weights);
+    // call parachain registry contract directly, as the owner of a parachain registration
+    Transaction::call(source, *REGISTRY_CONTRACT_ADDRESS, input).submit()
+}
+
+/// Encodes a call to `register`, with the owner of the registration as a multilocation relative to
+/// the evm parachain, which the contract converts to an address via the xcm-utils precompile.
+pub(crate) fn register_input(
+    para_id: impl Into<u32>,
+    pallet_instance: u8,
+    stake_amount: u128,
+    owner: &MultiLocation,
+    weights: &tellor::Weights,
+) -> Vec<u8> {
+    register_function()
+        .encode_input(&[
+            Token::Uint(para_id.into().into()),
+            Token::Uint(pallet_instance.into()),
+            Token::Uint(stake_amount.into()),
+            multilocation(owner),
+            Token::Tuple(vec![
+                Token::Uint(weights.report_stake_deposited.into()),
+                Token::Uint(weights.report_staking_withdraw_request.into()),
+                Token::Uint(weights.report_stake_withdrawn.into()),
+                Token::Uint(weights.report_vote_tallied.into()),
+                Token::Uint(weights.report_vote_executed.into()),
+                Token::Uint(weights.report_slash.into()),
+            ]),
+        ])
+        .unwrap()
+}
+
+/// Replaces the owner within the input of a `register` call, such as one sent by the tellor pallet,
+/// returning `None` when the input is not a call to `register`.
+pub(crate) fn with_owner(input: &[u8], owner: &MultiLocation) -> Option<Vec<u8>> {
+    let function = register_function();
+    if input.get(..4)? != function.short_signature() {
+        return None;
+    }
+    let mut tokens = function.decode_input(&input[4..]).ok()?;
+    tokens[3] = multilocation(owner);
+    function.encode_input(&tokens).ok()
+}
+
+fn register_function() -> Function {
+    #[allow(deprecated)]
+    Function {
+        name: "register".to_string(),
+        inputs: vec![
+            Param {
+                name: "_paraId".to_string(),
+                kind: ParamType::Uint(32),
+                internal_type: None,
+            },
+            Param {
+                name: "_palletInstance".to_string(),
+                kind: ParamType::Uint(8),
+                internal_type: None,
+            },
+            Param {
+                name: "_stakeAmount".to_string(),
+                kind: ParamType::Uint(256),
+                internal_type: None,
+            },
+            Param {
+                name: "_owner".to_string(),
+                kind: ParamType::Tuple(vec![
+                    ParamType::Uint(8),
+                    ParamType::Array(Box::new(ParamType::Bytes)),
+                ]),
+                internal_type: None,
+            },
+            Param {
+                name: "_weights".to_string(),
+                kind: weights_type(),
+                internal_type: None,
+            },
+        ],
+        outputs: vec![],
+        constant: None,
+        state_mutability: Default::default(),
+    }
+}
+
+// a multilocation as encoded by the xcm-utils precompile, with each junction prefixed by its type
+fn multilocation(location: &MultiLocation) -> Token {
+    let network = |network: &Option<NetworkId>| {
+        assert!(
+            network.is_none(),
+            "only junctions without network supported"
+        );
+        0u8
+    };
+    Token::Tuple(vec![
+        Token::Uint(location.parents.into()),
+        Token::Array(
+            location
+                .interior
+                .iter()
+                .map(|junction| {
+                    Token::Bytes(match junction {
+                        Junction::Parachain(id) => [&[0u8][..], &id.to_be_bytes()].concat(),
+                        Junction::AccountId32 { network: n, id } => {
+                            [&[1u8][..], id, &[network(n)]].concat()
+                        }
+                        Junction::AccountKey20 { network: n, key } => {
+                            [&[3u8][..], key, &[network(n)]].concat()
+                        }
+                        Junction::PalletInstance(index) => vec![4, *index],
+                        junction => unimplemented!("junction {junction:?} not supported"),
+                    })
+                })
+                .collect(),
+        ),
+    ])
+}
+
+pub(crate) fn get_by_id(para_id: impl Into<u32>) -> Parachain {
+    get(
+        "getById",
+        Param {
+            name: "_id".to_string(),
+            kind: ParamType::Uint(32),
+            internal_type: None,
+        },
+        Token::Uint(para_id.into().into()),
+    )
+}
+
+pub(crate) fn get_by_address(owner: Address) -> Parachain {
+    get(
+        "getByAddress",
+        Param {
+            name: "_address".to_string(),
+            kind: ParamType::Address,
+            internal_type: None,
+        },
+        Token::Address(owner),
+    )
+}
+
+fn get(name: &str, param: Param, token: Token) -> Parachain {
+    #[allow(deprecated)]
+    let function = Function {
+        name: name.to_string(),
+        inputs: vec![param],
+        outputs: vec![Param {
+            name: "".to_string(),
+            kind: ParamType::Tuple(vec![
+                ParamType::Uint(32),
+                ParamType::Address,
+                ParamType::Uint(8),
+                ParamType::Uint(256),
+                weights_type(),
+            ]),
+            internal_type: None,
+        }],
+        constant: None,
+        state_mutability: ethabi::StateMutability::View,
+    };
+    let input = function.encode_input(&vec![token]).unwrap();
+
+    // query parachain registry contract
+    let output = Transaction::call(*ALITH, *REGISTRY_CONTRACT_ADDRESS, input).view();
+    let parachain = function.decode_output(&output).unwrap()[0]
+        .clone()
+        .into_tuple()
+        .unwrap();
+    let weights: Vec<u64> = parachain[4]
+        .clone()
+        .into_tuple()
+        .unwrap()
+        .into_iter()
+        .map(|w| w.into_uint().unwrap().as_u64())
+        .collect();
+    Parachain {
+        id: parachain[0].clone().into_uint().unwrap().as_u32(),
+        owner: parachain[1].clone().into_address().unwrap(),
+        pallet_instance: parachain[2].clone().into_uint().unwrap().as_u32() as u8,
+        stake_amount: parachain[3].clone().into_uint().unwrap(),
+        weights: tellor::Weights {
+            report_stake_deposited: weights[0],
+            report_staking_withdraw_request: weights[1],
+            report_stake_withdrawn: weights[2],
+            report_vote_tallied: weights[3],
+            report_vote_executed: weights[4],
+            report_slash: weights[5],
+        },
+    }
+}
+
+fn weights_type() -> ParamType {
+    ParamType::Tuple(vec![ParamType::Uint(64); 6])
+}
+
+lazy_static! {
+    pub(crate) static ref EVENTS: Vec<Event> = vec![
+        Event {
+            name: "ParachainRegistered".to_string(),
+            // address caller, uint32 parachain, address owner
+            inputs: vec![
+                EventParam {
+                    name: "caller".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "parachain".to_string(),
+                    kind: ParamType::Uint(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "owner".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+    ];
+}
+
+fn event(name: &str) -> &'static Event {
+    EVENTS
+        .iter()
+        .find(|e| e.name == name)
+        .expect("event is defined; qed")
 }
 
 pub(crate) fn assert_executed(caller: Address) {
+    gas::record_transacted(caller);
     assert!(System::events().iter().any(|r| {
         match &r.event {
             RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed {
@@ -52,42 +304,20 @@ pub(crate) fn assert_executed(caller: Address) {
     }));
 }
 
-pub(crate) fn assert_parachain_registered_event(para_id: impl Into<u32>) {
-    let event = Event {
-        name: "ParachainRegistered".to_string(),
-        // address caller, uint32 parachain, address owner
-        inputs: vec![
-            EventParam {
-                name: "caller".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-            EventParam {
-                name: "parachain".to_string(),
-                kind: ParamType::Uint(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "owner".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+pub(crate) fn assert_parachain_registered_event(
+    caller: Address,
+    para_id: impl Into<u32>,
+    owner: Address,
+) {
+    let event = event("ParachainRegistered");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *REGISTRY_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::Address(*PALLET_DERIVATIVE_ACCOUNT),
-                    Token::Uint(para_id.into().into()),
-                    Token::Address(*PALLET_DERIVATIVE_ACCOUNT),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *REGISTRY_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::Address(caller),
+            Token::Uint(para_id.into().into()),
+            Token::Address(owner),
+        ]),
+    });
 }
@@ -4,38 +4,20 @@ use moonbeam_runtime::Assets;
 lazy_static! {
     pub(crate) static ref STAKING_CONTRACT_ADDRESS: Address =
         address_of("970951a12f975e6762482aca81e57d5a2a4e73f4");
+    // bytecode:object value from ParachainStaking.json, generated by forge build
+    static ref CONTRACT_BYTECODE: Vec<u8> = bytecode::load("ParachainStaking");
 }
 
-// bytecode:object value from ParachainStaking.json, generated by forge build
-const CONTRACT_BYTECODE: [u8; 9452] = {
-    with_builtin!(let $hex_str = include_str_from_root!("contracts/ParachainStaking.hex") in {
-        hex!($hex_str)
-    })
-};
-
 pub(crate) fn deploy(registry: Address, token: Address) {
     // use contract bytecode + constructor parameters
     let mut init = CONTRACT_BYTECODE.to_vec();
     init.append(encode(&vec![Token::Address(registry), Token::Address(token)]).as_mut());
 
     // create parachain staking contract
-    assert_ok!(EVM::create(
-        RuntimeOrigin::root(),
-        *ALITH,
-        init,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_last_event(
-        pallet_evm::Event::Created {
-            address: *STAKING_CONTRACT_ADDRESS,
-        }
-        .into(),
-    );
+    let receipt = Transaction::create(*ALITH, init).submit();
+    gas::record("ParachainStaking", "constructor", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Returned));
+    assert_eq!(receipt.to, *STAKING_CONTRACT_ADDRESS);
 }
 
 pub(crate) fn init(governance: Address) {
@@ -55,41 +37,23 @@ pub(crate) fn init(governance: Address) {
     .unwrap();
 
     // init parachain staking contract
-    assert_ok!(EVM::call(
-        RuntimeOrigin::root(),
-        *ALITH,
-        *STAKING_CONTRACT_ADDRESS,
-        input,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_has_event(
-        pallet_evm::Event::Executed {
-            address: *STAKING_CONTRACT_ADDRESS,
-        }
-        .into(),
-    );
-}
-
-pub(crate) fn approve(by: impl Into<AccountId20>, asset: u128, delegate: Address, amount: u128) {
-    assert_ok!(Assets::approve_transfer(
-        RuntimeOrigin::signed(by.into()),
-        asset.into(),
-        delegate.into(),
-        amount
-    ));
-    //todo: assert event
+    let receipt = Transaction::call(*ALITH, *STAKING_CONTRACT_ADDRESS, input).submit();
+    gas::record("ParachainStaking", "init", receipt.used_gas);
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
 }
 
+/// Mints the asset to the account via the assets pallet. The xcTRB precompile only implements the
+/// erc20 interface, which has no minting, and xcTRB is otherwise only minted when TRB is reserve
+/// transferred from the asset reserve parachain, so minting bypasses the precompile and no
+/// `Transfer` log is emitted.
 pub(crate) fn mint(asset: u128, who: impl Into<AccountId20>, amount: u128) {
-    use frame_support::traits::fungibles::Mutate;
+    use frame_support::traits::fungibles::{Inspect, Mutate};
 
-    assert_ok!(Assets::mint_into(asset, &who.into(), amount));
-    //todo: assert event
+    let who = who.into();
+    let (balance, total_issuance) = (Assets::balance(asset, &who), Assets::total_issuance(asset));
+    assert_ok!(Assets::mint_into(asset, &who, amount));
+    assert_eq!(Assets::balance(asset, &who), balance + amount);
+    assert_eq!(Assets::total_issuance(asset), total_issuance + amount);
 }
 
 pub(crate) fn deposit_parachain_stake(
@@ -98,6 +62,23 @@ pub(crate) fn deposit_parachain_stake(
     account: Vec<u8>,
     amount: u128,
 ) {
+    let receipt = try_deposit_parachain_stake(source, para_id, account, amount);
+    gas::record(
+        "ParachainStaking",
+        "depositParachainStake",
+        receipt.used_gas,
+    );
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
+}
+
+/// Deposits a stake without asserting the outcome, allowing failures such as an insufficient
+/// xcTRB allowance to be tested.
+pub(crate) fn try_deposit_parachain_stake(
+    source: Address,
+    para_id: impl Into<u32>,
+    account: Vec<u8>,
+    amount: u128,
+) -> Receipt {
     #[allow(deprecated)]
     let input = Function {
         name: "depositParachainStake".to_string(),
@@ -130,24 +111,7 @@ pub(crate) fn deposit_parachain_stake(
     .unwrap();
 
     // call parachain staking contract
-    assert_ok!(EVM::call(
-        RuntimeOrigin::root(),
-        source,
-        *STAKING_CONTRACT_ADDRESS,
-        input,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_has_event(
-        pallet_evm::Event::Executed {
-            address: *STAKING_CONTRACT_ADDRESS,
-        }
-        .into(),
-    );
+    Transaction::call(source, *STAKING_CONTRACT_ADDRESS, input).submit()
 }
 
 pub(crate) fn request_parachain_stake_withdraw(
@@ -181,24 +145,13 @@ pub(crate) fn request_parachain_stake_withdraw(
     .unwrap();
 
     // call parachain staking contract
-    assert_ok!(EVM::call(
-        RuntimeOrigin::root(),
-        source,
-        *STAKING_CONTRACT_ADDRESS,
-        input,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_has_event(
-        pallet_evm::Event::Executed {
-            address: *STAKING_CONTRACT_ADDRESS,
-        }
-        .into(),
+    let receipt = Transaction::call(source, *STAKING_CONTRACT_ADDRESS, input).submit();
+    gas::record(
+        "ParachainStaking",
+        "requestParachainStakeWithdraw",
+        receipt.used_gas,
     );
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
 }
 
 pub(crate) fn withdraw_parachain_stake(source: Address, para_id: impl Into<u32>) {
@@ -218,69 +171,229 @@ pub(crate) fn withdraw_parachain_stake(source: Address, para_id: impl Into<u32>)
     .unwrap();
 
     // call parachain staking contract
-    assert_ok!(EVM::call(
-        RuntimeOrigin::root(),
-        source,
-        *STAKING_CONTRACT_ADDRESS,
-        input,
-        U256::zero(),
-        GAS_LIMIT,
-        MAX_FEE_PER_GAS.into(),
-        None,
-        None,
-        Vec::new()
-    ));
-    System::assert_has_event(
-        pallet_evm::Event::Executed {
-            address: *STAKING_CONTRACT_ADDRESS,
-        }
-        .into(),
+    let receipt = Transaction::call(source, *STAKING_CONTRACT_ADDRESS, input).submit();
+    gas::record(
+        "ParachainStaking",
+        "withdrawParachainStake",
+        receipt.used_gas,
     );
+    assert_eq!(receipt.exit_reason, Succeed(Stopped));
 }
 
-pub(crate) fn assert_new_staker_event(staker: Address, amount: u128) {
-    let event = Event {
-        name: "NewStaker".to_string(),
+/// The stake of a staker for a parachain, as stored by the parachain staking contract.
+#[derive(Debug, PartialEq)]
+pub(crate) struct ParachainStakerInfo {
+    pub(crate) start_date: U256,
+    pub(crate) staked_balance: U256,
+    pub(crate) locked_balance: U256,
+}
+
+pub(crate) fn get_parachain_staker_info(
+    para_id: impl Into<u32>,
+    staker: Address,
+) -> ParachainStakerInfo {
+    #[allow(deprecated)]
+    let function = Function {
+        name: "getParachainStakerInfo".to_string(),
         inputs: vec![
-            EventParam {
-                name: "_staker".to_string(),
-                kind: ParamType::Address,
-                indexed: true,
+            Param {
+                name: "_paraId".to_string(),
+                kind: ParamType::Uint(32),
+                internal_type: None,
             },
-            EventParam {
-                name: "_amount".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: true,
+            Param {
+                name: "_stakerAddress".to_string(),
+                kind: ParamType::Address,
+                internal_type: None,
             },
         ],
-        anonymous: false,
+        outputs: vec![],
+        constant: None,
+        state_mutability: ethabi::StateMutability::View,
     };
+    let input = function
+        .encode_input(&vec![
+            Token::Uint(para_id.into().into()),
+            Token::Address(staker),
+        ])
+        .unwrap();
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *STAKING_CONTRACT_ADDRESS,
-                topics: vec![
-                    event.signature(),
-                    {
-                        let mut topic = [0u8; 32];
-                        topic[12..].clone_from_slice(&staker.0);
-                        topic
-                    }
-                    .into(),
-                    {
-                        let topic: [u8; 32] = encode(&vec![Token::Uint(amount.into())])
-                            .try_into()
-                            .unwrap();
-                        topic
-                    }
-                    .into(),
-                ],
-                data: Default::default(),
-            },
-        }
-        .into(),
-    );
+    // query parachain staking contract, decoding only the leading balances of the returned details
+    let output = Transaction::call(*ALITH, *STAKING_CONTRACT_ADDRESS, input).view();
+    let details = ethabi::decode(&[ParamType::Uint(256); 3], &output).unwrap();
+    let field = |i: usize| details[i].clone().into_uint().unwrap();
+    ParachainStakerInfo {
+        start_date: field(0),
+        staked_balance: field(1),
+        locked_balance: field(2),
+    }
+}
+
+lazy_static! {
+    pub(crate) static ref EVENTS: Vec<Event> = vec![
+        Event {
+            name: "NewStaker".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_staker".to_string(),
+                    kind: ParamType::Address,
+                    indexed: true,
+                },
+                EventParam {
+                    name: "_amount".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: true,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "NewParachainStaker".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_paraId".to_string(),
+                    kind: ParamType::Uint(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_staker".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_account".to_string(),
+                    kind: ParamType::Bytes,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_amount".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "ParachainReporterSlashed".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_paraId".to_string(),
+                    kind: ParamType::Uint(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_reporter".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_recipient".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_slashAmount".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "StakeWithdrawRequested".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_staker".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_amount".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "ParachainStakeWithdrawRequested".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_paraId".to_string(),
+                    kind: ParamType::Uint(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_account".to_string(),
+                    kind: ParamType::Bytes,
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_amount".to_string(),
+                    kind: ParamType::Uint(256),
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+        Event {
+            name: "StakeWithdrawn".to_string(),
+            inputs: vec![EventParam {
+                name: "_staker".to_string(),
+                kind: ParamType::Address,
+                indexed: false,
+            }],
+            anonymous: false,
+        },
+        Event {
+            name: "ParachainStakeWithdrawn".to_string(),
+            inputs: vec![
+                EventParam {
+                    name: "_paraId".to_string(),
+                    kind: ParamType::Uint(32),
+                    indexed: false,
+                },
+                EventParam {
+                    name: "_staker".to_string(),
+                    kind: ParamType::Address,
+                    indexed: false,
+                },
+            ],
+            anonymous: false,
+        },
+    ];
+}
+
+fn event(name: &str) -> &'static Event {
+    EVENTS
+        .iter()
+        .find(|e| e.name == name)
+        .expect("event is defined; qed")
+}
+
+pub(crate) fn assert_new_staker_event(staker: Address, amount: u128) {
+    let event = event("NewStaker");
+
+    logs::assert_has_log(ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![
+            event.signature(),
+            {
+                let mut topic = [0u8; 32];
+                topic[12..].clone_from_slice(&staker.0);
+                topic
+            }
+            .into(),
+            {
+                let topic: [u8; 32] = encode(&vec![Token::Uint(amount.into())])
+                    .try_into()
+                    .unwrap();
+                topic
+            }
+            .into(),
+        ],
+        data: Default::default(),
+    });
 }
 
 pub(crate) fn assert_new_parachain_staker_event(
@@ -289,48 +402,18 @@ pub(crate) fn assert_new_parachain_staker_event(
     account: Vec<u8>,
     amount: u128,
 ) {
-    let event = Event {
-        name: "NewParachainStaker".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_paraId".to_string(),
-                kind: ParamType::Uint(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_staker".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-            EventParam {
-                name: "_account".to_string(),
-                kind: ParamType::Bytes,
-                indexed: false,
-            },
-            EventParam {
-                name: "_amount".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("NewParachainStaker");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *STAKING_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::Uint(para_id.into().into()),
-                    Token::Address(staker),
-                    Token::Bytes(account),
-                    Token::Uint(amount.into()),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::Uint(para_id.into().into()),
+            Token::Address(staker),
+            Token::Bytes(account),
+            Token::Uint(amount.into()),
+        ]),
+    });
 }
 
 pub(crate) fn assert_parachain_reporter_slashed_event(
@@ -339,78 +422,28 @@ pub(crate) fn assert_parachain_reporter_slashed_event(
     recipient: Address,
     slash_amount: u128,
 ) {
-    let event = Event {
-        name: "ParachainReporterSlashed".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_paraId".to_string(),
-                kind: ParamType::Uint(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_reporter".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-            EventParam {
-                name: "_recipient".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-            EventParam {
-                name: "_slashAmount".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("ParachainReporterSlashed");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *STAKING_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::Uint(para_id.into().into()),
-                    Token::Address(reporter),
-                    Token::Address(recipient),
-                    Token::Uint(slash_amount.into()),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::Uint(para_id.into().into()),
+            Token::Address(reporter),
+            Token::Address(recipient),
+            Token::Uint(slash_amount.into()),
+        ]),
+    });
 }
 
 pub(crate) fn assert_stake_withdraw_requested_event(staker: Address, amount: u128) {
-    let event = Event {
-        name: "StakeWithdrawRequested".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_staker".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-            EventParam {
-                name: "_amount".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("StakeWithdrawRequested");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *STAKING_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![Token::Address(staker), Token::Uint(amount.into())]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![Token::Address(staker), Token::Uint(amount.into())]),
+    });
 }
 
 pub(crate) fn assert_parachain_stake_withdraw_requested_event(
@@ -418,96 +451,38 @@ pub(crate) fn assert_parachain_stake_withdraw_requested_event(
     account: Vec<u8>,
     amount: u128,
 ) {
-    let event = Event {
-        name: "ParachainStakeWithdrawRequested".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_paraId".to_string(),
-                kind: ParamType::Uint(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_account".to_string(),
-                kind: ParamType::Bytes,
-                indexed: false,
-            },
-            EventParam {
-                name: "_amount".to_string(),
-                kind: ParamType::Uint(256),
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("ParachainStakeWithdrawRequested");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *STAKING_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::Uint(para_id.into().into()),
-                    Token::Bytes(account),
-                    Token::Uint(amount.into()),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::Uint(para_id.into().into()),
+            Token::Bytes(account),
+            Token::Uint(amount.into()),
+        ]),
+    });
 }
 
 pub(crate) fn assert_stake_withdrawn_event(staker: Address) {
-    let event = Event {
-        name: "StakeWithdrawn".to_string(),
-        inputs: vec![EventParam {
-            name: "_staker".to_string(),
-            kind: ParamType::Address,
-            indexed: false,
-        }],
-        anonymous: false,
-    };
+    let event = event("StakeWithdrawn");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *STAKING_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![Token::Address(staker)]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![Token::Address(staker)]),
+    });
 }
 
 pub(crate) fn assert_parachain_stake_withdrawn_event(para_id: impl Into<u32>, staker: Address) {
-    let event = Event {
-        name: "ParachainStakeWithdrawn".to_string(),
-        inputs: vec![
-            EventParam {
-                name: "_paraId".to_string(),
-                kind: ParamType::Uint(32),
-                indexed: false,
-            },
-            EventParam {
-                name: "_staker".to_string(),
-                kind: ParamType::Address,
-                indexed: false,
-            },
-        ],
-        anonymous: false,
-    };
+    let event = event("ParachainStakeWithdrawn");
 
-    System::assert_has_event(
-        pallet_evm::Event::Log {
-            log: ethereum::Log {
-                address: *STAKING_CONTRACT_ADDRESS,
-                topics: vec![event.signature()],
-                data: encode(&vec![
-                    Token::Uint(para_id.into().into()),
-                    Token::Address(staker),
-                ]),
-            },
-        }
-        .into(),
-    );
+    logs::assert_has_log(ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: encode(&vec![
+            Token::Uint(para_id.into().into()),
+            Token::Address(staker),
+        ]),
+    });
 }
@@ -0,0 +1,152 @@
+use super::*;
+use codec::{Decode, Encode};
+use frame_support::{
+    storage::{with_transaction, TransactionOutcome},
+    traits::Get,
+    weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight},
+};
+use moonbeam_runtime::{
+    xcm_config::{SelfReserve, XcmExecutorConfig},
+    Balance,
+};
+use sp_runtime::DispatchError;
+use xcm::prelude::{GeneralIndex, PalletInstance};
+use xcm::v3::{AssetId::Concrete, Junctions, MultiAsset, MultiAssets};
+use xcm_executor::{traits::WeightTrader, Assets};
+
+/// Assumed price of TRB in GLMR, used to set the units per second charged in xcTRB for xcm
+/// execution. This is not a market price: the asset manager only stores a fixed rate, set by
+/// governance on a live chain, so any fixed value exercises the same fee path. Fees charged in xcTRB
+/// are therefore only checked relative to the native fee, which is derived from the runtime.
+pub(crate) const TRB_PRICE_IN_GLMR: u128 = 50;
+
+/// The location of GLMR, the native asset of the evm parachain.
+pub(crate) fn native_asset() -> MultiLocation {
+    SelfReserve::get()
+}
+
+/// The location of xcTRB, as registered with the asset manager.
+pub(crate) fn xctrb_asset() -> MultiLocation {
+    MultiLocation {
+        parents: 1,
+        interior: Junctions::X3(Parachain(1_000), PalletInstance(50), GeneralIndex(872)),
+    }
+}
+
+/// The fee charged for purchasing the weight in the asset, using the runtime's xcm trader.
+pub(crate) fn fee(asset: MultiLocation, weight: Weight) -> Balance {
+    const PAYMENT: Balance = Balance::MAX / 2;
+    // the trader deposits the fee when dropped, so changes are rolled back
+    with_transaction(|| {
+        let mut trader = <XcmExecutorConfig as xcm_executor::Config>::Trader::new();
+        let unused = trader
+            .buy_weight(weight, Assets::from(MultiAsset::from((asset, PAYMENT))))
+            .expect("weight purchased");
+        let fee = PAYMENT
+            - unused
+                .fungible
+                .get(&Concrete(asset))
+                .copied()
+                .unwrap_or_default();
+        TransactionOutcome::Rollback(Ok::<_, DispatchError>(fee))
+    })
+    .expect("fee calculated")
+}
+
+/// The weight of the message from a sibling parachain, as determined by the runtime's weigher
+/// before buying execution.
+pub(crate) fn message_weight(message: Xcm<()>) -> Weight {
+    use xcm_executor::traits::WeightBounds;
+    let mut message = Xcm::<moonbeam_runtime::RuntimeCall>::from(message);
+    <XcmExecutorConfig as xcm_executor::Config>::Weigher::weight(&mut message)
+        .expect("message weighed")
+}
+
+pub(crate) fn native_fee(weight: Weight) -> Balance {
+    fee(native_asset(), weight)
+}
+
+pub(crate) fn xctrb_fee(weight: Weight) -> Balance {
+    fee(xctrb_asset(), weight)
+}
+
+/// The xcTRB units charged per second of execution, equivalent to the native fee at the assumed
+/// TRB price.
+pub(crate) fn xctrb_units_per_second() -> u128 {
+    native_fee(Weight::from_parts(WEIGHT_REF_TIME_PER_SECOND, 0)) / TRB_PRICE_IN_GLMR
+}
+
+/// The weight of each xcm message from a sibling parachain successfully executed since the event
+/// with the given index.
+pub(crate) fn executed_xcm_weights(since: usize) -> Vec<Weight> {
+    System::events()
+        .into_iter()
+        .skip(since)
+        .filter_map(|r| match r.event {
+            // the runtime uses a fork of the xcmp queue pallet which cannot be named, so its
+            // `Success { message_hash, weight }` event (index 0) is decoded from its encoding
+            RuntimeEvent::XcmpQueue(event) => match event.encode().split_first() {
+                Some((0, mut data)) => Some(
+                    <(Option<[u8; 32]>, Weight)>::decode(&mut data)
+                        .expect("xcmp queue success event is valid; qed")
+                        .1,
+                ),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The error of each xcm message from a sibling parachain which failed to execute since the event
+/// with the given index.
+pub(crate) fn failed_xcm_errors(since: usize) -> Vec<xcm::v3::Error> {
+    System::events()
+        .into_iter()
+        .skip(since)
+        .filter_map(|r| match r.event {
+            // `Fail { message_hash, error, weight }` event (index 1), decoded as above
+            RuntimeEvent::XcmpQueue(event) => match event.encode().split_first() {
+                Some((1, mut data)) => Some(
+                    <(Option<[u8; 32]>, xcm::v3::Error, Weight)>::decode(&mut data)
+                        .expect("xcmp queue fail event is valid; qed")
+                        .1,
+                ),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// The origin and assets of each `AssetsTrapped` event since the event with the given index.
+pub(crate) fn trapped_assets(since: usize) -> Vec<(MultiLocation, MultiAssets)> {
+    System::events()
+        .into_iter()
+        .skip(since)
+        .filter_map(|r| match r.event {
+            RuntimeEvent::PolkadotXcm(pallet_xcm::Event::AssetsTrapped(_, origin, assets)) => {
+                Some((
+                    origin,
+                    assets.try_into().expect("trapped assets are v3; qed"),
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Asserts that the account was charged exactly the native fee for each xcm message executed since
+/// the event with the given index, returning the total fee.
+pub(crate) fn assert_charged_for_executed_xcm(
+    account: Address,
+    balance: Balance,
+    since: usize,
+) -> Balance {
+    let weights = executed_xcm_weights(since);
+    assert!(!weights.is_empty(), "no xcm executed");
+    contracts::gas::record_transacted(account);
+    let fee = weights.into_iter().map(native_fee).sum();
+    assert_eq!(balance - transaction::balance_of(account), fee);
+    fee
+}
@@ -3,15 +3,18 @@ use account::AccountId20;
 use core::time::Duration;
 use frame_support::{assert_ok, traits::UnixTime};
 use moonbeam_runtime::{
-    asset_config::AssetRegistrarMetadata, xcm_config::AssetType, AssetManager, BalancesConfig,
-    EVMConfig, GenesisAccount, GenesisConfig, ParachainInfoConfig, PolkadotXcmConfig, Precompiles,
-    Runtime, RuntimeEvent, RuntimeOrigin, System, SystemConfig, Timestamp, EVM, WASM_BINARY,
+    asset_config::AssetRegistrarMetadata, xcm_config::AssetType, AssetManager, Assets, Balances,
+    BalancesConfig, EVMConfig, GenesisAccount, GenesisConfig, ParachainInfoConfig,
+    PolkadotXcmConfig, Precompiles, Runtime, RuntimeEvent, RuntimeOrigin, System, SystemConfig,
+    Timestamp, WASM_BINARY,
 };
 use sp_runtime::{app_crypto::sp_core::bytes::from_hex, app_crypto::sp_core::H160};
-use xcm::prelude::{GeneralIndex, PalletInstance, Parachain};
-use xcm::v3::{Junctions, MultiLocation};
+use xcm::prelude::{PalletInstance, Parachain, X2};
+use xcm::v3::MultiLocation;
 
 pub(crate) mod contracts;
+pub(crate) mod fees;
+pub(crate) mod transaction;
 
 lazy_static! {
     // https://github.com/moonbeam-foundation/moonbeam#prefunded-development-addresses
@@ -27,6 +30,30 @@ lazy_static! {
         address_of("26ab7997cf6d531fed12b2fa6bc3cf2248724195");
     pub(crate) static ref XCTRB_ADDRESS: Address =
         address_of("ffffffffc8be577a279484431b9444687ec3d2ae");
+    // asset id of xcTRB, from which the precompile address is derived
+    pub(crate) static ref XCTRB_ASSET_ID: u128 =
+        u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+}
+
+/// The balance of the pallet derivative account at genesis, which must cover the xcm fees of all
+/// messages sent by the tellor pallet (see `fees`).
+pub(crate) const PALLET_DERIVATIVE_ACCOUNT_FUNDING: u128 = 10u128.pow(18);
+
+/// The account derived from the tellor pallet on a consumer parachain, which pays the xcm fees of
+/// messages sent by the pallet.
+pub(crate) fn pallet_derivative_account(para_id: u32) -> Address {
+    use frame_support::traits::PalletInfoAccess;
+    use moonbeam_runtime::xcm_config::LocationToAccountId;
+    let location = MultiLocation::new(
+        1,
+        X2(
+            Parachain(para_id),
+            PalletInstance(<oracle_consumer_runtime::Tellor as PalletInfoAccess>::index() as u8),
+        ),
+    );
+    <LocationToAccountId as Convert<MultiLocation, AccountId20>>::convert(location)
+        .expect("location converts to account; qed")
+        .into()
 }
 
 fn address_of(address: &str) -> H160 {
@@ -34,8 +61,15 @@ fn address_of(address: &str) -> H160 {
     H160::from_str(address).expect("internal H160 is valid; qed")
 }
 
+/// Environment variable pointing at a raw chain spec or key/value dump of the evm parachain, used
+/// as its genesis instead of the genesis config below.
+pub(crate) const GENESIS_FILE: &str = "EVM_PARACHAIN_GENESIS";
+
 pub(crate) fn genesis() -> Storage {
     const PARA_ID: ParaId = ParaId::new(2_000);
+    if let Some(storage) = crate::chain_spec::genesis_from_file(GENESIS_FILE, PARA_ID) {
+        return storage;
+    }
 
     // set precompiles revert bytecode: https://github.com/PureStake/moonbeam/blob/a814fcf36a67f0f14f40afcd7d12fd4f3c5e775b/node/service/src/chain_spec/moonbeam.rs#L244
     let revert_bytecode = vec![0x60, 0x00, 0x60, 0x00, 0xFD];
@@ -48,12 +82,19 @@ pub(crate) fn genesis() -> Storage {
             ..Default::default()
         },
         balances: BalancesConfig {
+            // transactions require gas_limit * max_fee_per_gas to be available upfront
             balances: vec![
-                ((*ALITH).into(), 2 * 10u128.saturating_pow(18)), // contract deployment
-                ((*BALTHAZAR).into(), 2 * 10u128.saturating_pow(18)), // contract transactions
+                ((*ALITH).into(), 100 * 10u128.saturating_pow(18)), // contract deployment
+                ((*BALTHAZAR).into(), 100 * 10u128.saturating_pow(18)), // contract transactions
+                ((*CHARLETH).into(), 100 * 10u128.saturating_pow(18)), // contract transactions
+                ((*DOROTHY).into(), 100 * 10u128.saturating_pow(18)), // contract transactions
                 (
                     (*PALLET_DERIVATIVE_ACCOUNT).into(),
-                    1 * 10u128.saturating_pow(18), // required for xcm fees
+                    PALLET_DERIVATIVE_ACCOUNT_FUNDING, // required for xcm fees
+                ),
+                (
+                    pallet_derivative_account(oracle_consumer::SECOND_PARA_ID).into(),
+                    PALLET_DERIVATIVE_ACCOUNT_FUNDING, // required for xcm fees
                 ),
             ],
         },
@@ -102,11 +143,19 @@ pub(crate) fn advance_time(time_in_secs: u64) {
     );
 }
 
+/// Sets the free balance of an account, allowing accounts funded at genesis to be drained or
+/// topped up.
+pub(crate) fn set_balance(who: Address, amount: u128) {
+    assert_ok!(Balances::force_set_balance(
+        RuntimeOrigin::root(),
+        who.into(),
+        amount
+    ));
+    assert_eq!(transaction::balance_of(who), amount);
+}
+
 pub(crate) fn create_xctrb_asset() {
-    let asset = AssetType::Xcm(MultiLocation {
-        parents: 1,
-        interior: Junctions::X3(Parachain(1_000), PalletInstance(50), GeneralIndex(872)),
-    });
+    let asset = AssetType::Xcm(fees::xctrb_asset());
     let metadata = AssetRegistrarMetadata {
         name: b"Tellor Tribute".to_vec(),
         symbol: b"xcTRB".to_vec(),
@@ -130,13 +179,14 @@ pub(crate) fn create_xctrb_asset() {
         }
         .into(),
     );
-    // set units per second
-    let units_per_second = 100_000; // todo: size correctly
+    // set units per second, with the weight hint bounding the fee payment assets already supported
+    let units_per_second = fees::xctrb_units_per_second();
+    let supported_assets = AssetManager::supported_fee_payment_assets().len() as u32;
     assert_ok!(AssetManager::set_asset_units_per_second(
         RuntimeOrigin::root(),
         asset.clone(),
         units_per_second,
-        5, // todo: size correctly
+        supported_assets + 1,
     ));
     System::assert_last_event(
         pallet_asset_manager::Event::UnitsPerSecondChanged {
@@ -155,3 +205,33 @@ pub(crate) fn create_xctrb_asset() {
         )]
     ));
 }
+
+pub(crate) fn xctrb_balance(who: Address) -> u128 {
+    use frame_support::traits::fungibles::Inspect;
+    <Assets as Inspect<AccountId20>>::balance(*XCTRB_ASSET_ID, &who.into())
+}
+
+/// Transfers xcTRB back to an account on the asset reserve parachain, with xcm fees paid in trb.
+pub(crate) fn transfer_xctrb_to_reserve(from: Address, to: sp_runtime::AccountId32, amount: u128) {
+    use moonbeam_runtime::{xcm_config::CurrencyId, XTokens};
+    let dest = MultiLocation::new(
+        1,
+        X2(
+            Parachain(AssetReserveParachain::para_id().into()),
+            AccountId32 {
+                network: None,
+                id: to.into(),
+            },
+        ),
+    );
+    let balance = xctrb_balance(from);
+    assert_ok!(XTokens::transfer(
+        RuntimeOrigin::signed(from.into()),
+        CurrencyId::ForeignAsset(*XCTRB_ASSET_ID),
+        amount,
+        Box::new(VersionedMultiLocation::V3(dest)),
+        Unlimited
+    ));
+    // ensure xcTRB burnt
+    assert_eq!(xctrb_balance(from), balance - amount);
+}
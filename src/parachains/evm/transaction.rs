@@ -0,0 +1,272 @@
+use super::*;
+use codec::Encode;
+use ethereum::{
+    EIP1559Transaction, EIP1559TransactionMessage, ReceiptV3, TransactionAction, TransactionV2,
+};
+use fp_self_contained::SelfContainedCall;
+use frame_support::{
+    dispatch::GetDispatchInfo,
+    storage::{with_transaction, TransactionOutcome},
+    traits::Get,
+};
+use moonbeam_runtime::{Balance, Balances, RuntimeCall};
+use pallet_evm::{CallInfo, ExitReason, ExitSucceed, FeeCalculator, Runner};
+use sp_runtime::{
+    app_crypto::sp_core::{H256, U256},
+    DispatchError,
+};
+
+pub(crate) const GAS_LIMIT: u64 = 10_000_000;
+pub(crate) const MAX_FEE_PER_GAS: u128 = 125_000_000_000;
+
+/// An EIP-1559 transaction, signed by one of the prefunded development accounts and submitted via
+/// `pallet_ethereum::Call::transact`, as a reporter would when using a wallet such as MetaMask.
+pub(crate) struct Transaction {
+    from: Address,
+    action: TransactionAction,
+    input: Vec<u8>,
+    value: U256,
+    gas_limit: u64,
+    max_fee_per_gas: u128,
+    max_priority_fee_per_gas: u128,
+}
+
+/// The outcome of a submitted transaction.
+#[derive(Debug)]
+pub(crate) struct Receipt {
+    /// `None` when the call was dispatched directly rather than submitted as a signed transaction.
+    pub(crate) transaction_hash: Option<H256>,
+    /// The called contract, or the address of the created contract.
+    pub(crate) to: Address,
+    pub(crate) exit_reason: ExitReason,
+    pub(crate) used_gas: U256,
+    /// The amount charged to the sender for gas.
+    pub(crate) fee: Balance,
+}
+
+impl Transaction {
+    pub(crate) fn call(from: Address, to: Address, input: Vec<u8>) -> Self {
+        Self::new(from, TransactionAction::Call(to), input)
+    }
+
+    pub(crate) fn create(from: Address, init: Vec<u8>) -> Self {
+        Self::new(from, TransactionAction::Create, init)
+    }
+
+    fn new(from: Address, action: TransactionAction, input: Vec<u8>) -> Self {
+        Self {
+            from,
+            action,
+            input,
+            value: U256::zero(),
+            gas_limit: GAS_LIMIT,
+            max_fee_per_gas: MAX_FEE_PER_GAS,
+            max_priority_fee_per_gas: 0,
+        }
+    }
+
+    pub(crate) fn value(mut self, value: impl Into<U256>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub(crate) fn gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = gas_limit;
+        self
+    }
+
+    pub(crate) fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: u128) -> Self {
+        self.max_priority_fee_per_gas = max_priority_fee_per_gas;
+        self
+    }
+
+    /// Signs the transaction using the next nonce of the sender.
+    pub(crate) fn sign(self) -> TransactionV2 {
+        let message = EIP1559TransactionMessage {
+            chain_id: <Runtime as pallet_evm::Config>::ChainId::get(),
+            nonce: nonce_of(self.from),
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas.into(),
+            max_fee_per_gas: self.max_fee_per_gas.into(),
+            gas_limit: self.gas_limit.into(),
+            action: self.action,
+            value: self.value,
+            input: self.input,
+            access_list: Vec::new(),
+        };
+        let signature = signer_of(self.from).sign_prehashed(&message.hash().0);
+        TransactionV2::EIP1559(EIP1559Transaction {
+            chain_id: message.chain_id,
+            nonce: message.nonce,
+            max_priority_fee_per_gas: message.max_priority_fee_per_gas,
+            max_fee_per_gas: message.max_fee_per_gas,
+            gas_limit: message.gas_limit,
+            action: message.action,
+            value: message.value,
+            input: message.input,
+            access_list: message.access_list,
+            odd_y_parity: signature.0[64] != 0,
+            r: H256::from_slice(&signature.0[..32]),
+            s: H256::from_slice(&signature.0[32..64]),
+        })
+    }
+
+    /// Signs and submits the transaction, ensuring that the sender nonce is incremented and that
+    /// the sender is charged for the gas used.
+    pub(crate) fn submit(self) -> Receipt {
+        let from = self.from;
+        let value = self.value;
+        let gas_price = {
+            let (base_fee, _) = <Runtime as pallet_evm::Config>::FeeCalculator::min_gas_price();
+            let max_fee_per_gas = U256::from(self.max_fee_per_gas);
+            assert!(
+                max_fee_per_gas >= base_fee,
+                "max fee per gas below base fee"
+            );
+            base_fee + U256::from(self.max_priority_fee_per_gas).min(max_fee_per_gas - base_fee)
+        };
+        let nonce = nonce_of(from);
+        let balance = balance_of(from);
+
+        let transaction = self.sign();
+        let transaction_hash = transaction.hash();
+        let call = RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction });
+
+        // recover sender from signature, validate and then apply, as per block execution
+        let source = call
+            .check_self_contained()
+            .expect("ethereum transaction is self-contained; qed")
+            .expect("transaction signature is valid");
+        assert_eq!(source, from);
+        let dispatch_info = call.get_dispatch_info();
+        assert_ok!(call
+            .validate_self_contained(&source, &dispatch_info, call.encoded_size())
+            .expect("ethereum transaction is self-contained; qed"));
+        assert_ok!(call
+            .apply_self_contained(source)
+            .expect("ethereum transaction is self-contained; qed"));
+
+        let (to, exit_reason) = System::events()
+            .into_iter()
+            .find_map(|r| match r.event {
+                RuntimeEvent::Ethereum(pallet_ethereum::Event::Executed {
+                    to,
+                    transaction_hash: hash,
+                    exit_reason,
+                    ..
+                }) if hash == transaction_hash => Some((to, exit_reason)),
+                _ => None,
+            })
+            .expect("transaction executed");
+
+        // receipts report the cumulative gas used within the current block
+        let used_gas = match pallet_ethereum::Pending::<Runtime>::get().as_slice() {
+            [.., (_, _, previous), (_, _, receipt)] => {
+                cumulative_gas_used(receipt) - cumulative_gas_used(previous)
+            }
+            [(_, _, receipt)] => cumulative_gas_used(receipt),
+            [] => panic!("no pending receipt for transaction"),
+        };
+
+        // ensure nonce incremented and sender charged for gas used
+        assert_eq!(nonce_of(from), nonce + 1);
+        let fee = (used_gas * gas_price).as_u128();
+        assert_eq!(balance - balance_of(from), fee + value.as_u128());
+
+        Receipt {
+            transaction_hash: Some(transaction_hash),
+            to,
+            exit_reason,
+            used_gas,
+            fee,
+        }
+    }
+
+    /// Executes the call directly via the EVM runner rather than as a signed transaction, allowing
+    /// calls from accounts without a known private key such as the pallet derivative account, whose
+    /// calls otherwise only arrive via XCM `Transact`. As the fees of such calls are paid via XCM,
+    /// the sender is not charged for gas, leaving its balance for the xcm fees of later messages.
+    pub(crate) fn dispatch(self) -> Receipt {
+        let TransactionAction::Call(to) = self.action else {
+            panic!("only calls can be dispatched")
+        };
+        let from = self.from;
+        let value = self.value;
+        let balance = balance_of(from);
+
+        let info = self.execute(to);
+
+        // ensure sender only charged for any value transferred
+        assert_eq!(balance - balance_of(from), value.as_u128());
+
+        Receipt {
+            transaction_hash: None,
+            to,
+            exit_reason: info.exit_reason,
+            used_gas: info.used_gas.standard,
+            fee: 0,
+        }
+    }
+
+    /// Executes the call without persisting any state changes, as per `eth_call`, returning the
+    /// output of a view function.
+    pub(crate) fn view(self) -> Vec<u8> {
+        let TransactionAction::Call(to) = self.action else {
+            panic!("only calls can be viewed")
+        };
+        let info = with_transaction(|| {
+            TransactionOutcome::Rollback(Ok::<_, DispatchError>(self.execute(to)))
+        })
+        .expect("view executed");
+        assert_eq!(
+            info.exit_reason,
+            ExitReason::Succeed(ExitSucceed::Returned),
+            "view reverted: {:?}",
+            info.value
+        );
+        info.value
+    }
+
+    // executes the call without validation or charging fees, as no fee per gas is specified for a
+    // non-transactional call
+    fn execute(self, to: Address) -> CallInfo {
+        <Runtime as pallet_evm::Config>::Runner::call(
+            self.from,
+            to,
+            self.input,
+            self.value,
+            self.gas_limit,
+            None, // max_fee_per_gas
+            None, // max_priority_fee_per_gas
+            None,
+            Vec::new(),
+            false, // is_transactional
+            false, // validate
+            None,
+            None,
+            <Runtime as pallet_evm::Config>::config(),
+        )
+        .expect("call executed")
+    }
+}
+
+pub(crate) fn nonce_of(address: Address) -> U256 {
+    System::account_nonce(AccountId20::from(address)).into()
+}
+
+pub(crate) fn balance_of(address: Address) -> Balance {
+    Balances::free_balance(AccountId20::from(address))
+}
+
+pub(crate) fn cumulative_gas_used(receipt: &ReceiptV3) -> U256 {
+    match receipt {
+        ReceiptV3::Legacy(data) | ReceiptV3::EIP2930(data) | ReceiptV3::EIP1559(data) => {
+            data.used_gas
+        }
+    }
+}
+
+fn signer_of(address: Address) -> ecdsa::Pair {
+    keyring::Keyring::from_address(address)
+        .unwrap_or_else(|| panic!("no private key available for {address:?}"))
+        .evm_pair()
+}
@@ -0,0 +1,235 @@
+use super::*;
+use evm::contracts::{erc20, governance, logs, staking};
+use oracle_consumer_runtime::{AccountId, RuntimeEvent, System, Tellor};
+use sp_core::{H256, U256};
+use std::{collections::BTreeMap, fmt};
+
+/// A disagreement between the state of the contracts on the evm parachain and the tellor pallet on
+/// the oracle consumer parachain.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Mismatch {
+    pub(crate) subject: String,
+    pub(crate) evm: String,
+    pub(crate) oracle_consumer: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} on evm parachain, {} on oracle consumer parachain",
+            self.subject, self.evm, self.oracle_consumer
+        )
+    }
+}
+
+/// Checks that the staking and governance contracts agree with the tellor pallet on the stakes of
+/// the given stakers and the given disputes, reporting every mismatch rather than only the first.
+/// Checking executes on both parachains, so any pending messages are routed beforehand; it must
+/// therefore not be called within `execute_with`.
+///
+/// Locked balances are only checked to be held by the staking contract, as the tellor pallet
+/// lowers the stake amount on a withdrawal request rather than recording the locked balance. The
+/// initiators of disputes are not compared, as the governance contract records the beneficiary
+/// address given when beginning the dispute rather than the initiating account.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct InvariantChecker {
+    stakers: Vec<(AccountId, Address)>,
+    disputes: Vec<H256>,
+}
+
+// the disputed reporter and the result of the latest tallied vote round of a dispute, as recorded
+// by one of the parachains
+#[derive(Clone, Debug)]
+struct DisputeState<Reporter> {
+    reporter: Option<Reporter>,
+    result: Option<u8>,
+}
+
+impl<Reporter> Default for DisputeState<Reporter> {
+    fn default() -> Self {
+        Self {
+            reporter: None,
+            result: None,
+        }
+    }
+}
+
+impl InvariantChecker {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Includes the stake of the staker address on the evm parachain, reported to the tellor pallet
+    /// for the account.
+    pub(crate) fn staker(mut self, account: AccountId, staker: Address) -> Self {
+        self.add_staker(account, staker);
+        self
+    }
+
+    pub(crate) fn add_staker(&mut self, account: AccountId, staker: Address) {
+        if !self
+            .stakers
+            .iter()
+            .any(|(a, s)| *a == account && *s == staker)
+        {
+            self.stakers.push((account, staker));
+        }
+    }
+
+    /// Includes the dispute, which should have the same number of vote rounds on both parachains.
+    pub(crate) fn dispute(mut self, dispute_id: H256) -> Self {
+        self.add_dispute(dispute_id);
+        self
+    }
+
+    pub(crate) fn add_dispute(&mut self, dispute_id: H256) {
+        if !self.disputes.contains(&dispute_id) {
+            self.disputes.push(dispute_id);
+        }
+    }
+
+    /// Returns every mismatch between the parachains.
+    pub(crate) fn check(&self) -> Vec<Mismatch> {
+        let para_id = OracleConsumerParachain::para_id();
+        let (stakes, vote_rounds, held, owed) = EvmParachain::execute_with(|| {
+            let stakes: Vec<_> = self
+                .stakers
+                .iter()
+                .map(|(_, staker)| staking::get_parachain_staker_info(para_id, *staker))
+                .collect();
+            let vote_rounds: Vec<U256> = self
+                .disputes
+                .iter()
+                .map(|id| governance::get_vote_rounds(*id))
+                .collect();
+            let held = erc20::balance_of(*staking::STAKING_CONTRACT_ADDRESS);
+            let owed = stakes.iter().fold(U256::zero(), |total, s| {
+                total + s.staked_balance + s.locked_balance
+            });
+            (stakes, vote_rounds, held, owed)
+        });
+        let evm_disputes = EvmParachain::execute_with(|| self.evm_disputes(para_id.into()));
+
+        let mut mismatches = Vec::new();
+        // the staking contract must hold at least the staked and locked balances of all stakers
+        if held < owed {
+            mismatches.push(Mismatch {
+                subject: "staking contract xcTRB balance".to_string(),
+                evm: format!("{held} held for {owed} staked and locked"),
+                oracle_consumer: "-".to_string(),
+            });
+        }
+        OracleConsumerParachain::execute_with(|| {
+            for ((account, staker), info) in self.stakers.iter().zip(&stakes) {
+                let stake_amount = oracle_consumer::stake_amount(account);
+                if info.staked_balance != stake_amount {
+                    mismatches.push(Mismatch {
+                        subject: format!("stake of {staker:?} for {account}"),
+                        evm: info.staked_balance.to_string(),
+                        oracle_consumer: stake_amount.to_string(),
+                    });
+                }
+            }
+            for (dispute_id, rounds) in self.disputes.iter().zip(&vote_rounds) {
+                let pallet_rounds = Tellor::get_vote_rounds(*dispute_id);
+                if *rounds != pallet_rounds.into() {
+                    mismatches.push(Mismatch {
+                        subject: format!("vote rounds of dispute {dispute_id:?}"),
+                        evm: rounds.to_string(),
+                        oracle_consumer: pallet_rounds.to_string(),
+                    });
+                }
+            }
+            let pallet_disputes = self.pallet_disputes();
+            for dispute_id in &self.disputes {
+                let evm = evm_disputes.get(dispute_id).cloned().unwrap_or_default();
+                let pallet = pallet_disputes.get(dispute_id).cloned().unwrap_or_default();
+                // a dispute unknown to either parachain is reported as a mismatch of vote rounds
+                if let (Some(staker), Some(account)) = (evm.reporter, pallet.reporter) {
+                    if !self.stakers.contains(&(account.clone(), staker)) {
+                        mismatches.push(Mismatch {
+                            subject: format!("reporter of dispute {dispute_id:?}"),
+                            evm: format!("{staker:?}"),
+                            oracle_consumer: account.to_string(),
+                        });
+                    }
+                }
+                if evm.result != pallet.result {
+                    let describe =
+                        |result: Option<u8>| result.map_or("-".to_string(), |r| r.to_string());
+                    mismatches.push(Mismatch {
+                        subject: format!("result of dispute {dispute_id:?}"),
+                        evm: describe(evm.result),
+                        oracle_consumer: describe(pallet.result),
+                    });
+                }
+            }
+        });
+        mismatches
+    }
+
+    // the reporter and latest tallied result of each dispute, as emitted by the governance
+    // contract. Must be executed on the evm parachain.
+    fn evm_disputes(&self, para_id: u32) -> BTreeMap<H256, DisputeState<Address>> {
+        let mut disputes = BTreeMap::<H256, DisputeState<Address>>::new();
+        for log in logs::decoded()
+            .into_iter()
+            .filter(|l| l.address == *governance::GOVERNANCE_CONTRACT_ADDRESS)
+        {
+            let param = |name: &str| log.param(name).cloned().expect("event has parameter");
+            match log.name.as_str() {
+                "NewParachainDispute" => {
+                    if param("_paraId").into_uint() != Some(para_id.into()) {
+                        continue;
+                    }
+                    let query_id = H256::from_slice(&param("_queryId").into_fixed_bytes().unwrap());
+                    let timestamp = param("_timestamp").into_uint().unwrap().as_u64();
+                    let id = dispute::dispute_id(para_id, query_id, timestamp);
+                    disputes.entry(id).or_default().reporter = param("_reporter").into_address();
+                }
+                "VoteTallied" => {
+                    let id = H256::from_slice(&param("_disputeId").into_fixed_bytes().unwrap());
+                    disputes.entry(id).or_default().result =
+                        param("_result").into_uint().map(|r| r.as_u32() as u8);
+                }
+                _ => {}
+            }
+        }
+        disputes
+    }
+
+    // the reporter and latest tallied result of each dispute, as emitted by the tellor pallet.
+    // Must be executed on the oracle consumer parachain.
+    fn pallet_disputes(&self) -> BTreeMap<H256, DisputeState<AccountId>> {
+        let mut disputes = BTreeMap::<H256, DisputeState<AccountId>>::new();
+        for record in System::events() {
+            match record.event {
+                RuntimeEvent::Tellor(tellor::Event::NewDispute {
+                    dispute_id,
+                    reporter,
+                    ..
+                }) => disputes.entry(dispute_id).or_default().reporter = Some(reporter),
+                RuntimeEvent::Tellor(tellor::Event::VoteTallied {
+                    dispute_id, result, ..
+                }) => disputes.entry(dispute_id).or_default().result = Some(result as u8),
+                _ => {}
+            }
+        }
+        disputes
+    }
+
+    /// Asserts that the parachains agree, listing every mismatch on failure.
+    pub(crate) fn assert_consistent(&self) {
+        let mismatches = self.check();
+        assert!(
+            mismatches.is_empty(),
+            "parachains disagree:\n{}",
+            mismatches
+                .iter()
+                .map(|m| format!("  {m}"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use super::*;
+use frame_support::{assert_ok, traits::fungible::Mutate};
+use sp_runtime::AccountId32;
+
+/// A named actor, with an sr25519 account on the relay chain, asset reserve and oracle consumer
+/// parachains and an ecdsa address on the evm parachain, such as a reporter's consumer account and
+/// staker address. The dev accounts of each are paired in order, so Bob stakes as Balthazar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Keyring {
+    Alice,
+    Bob,
+    Charlie,
+    Dave,
+}
+
+/// The amounts minted to an actor on each chain by `Keyring::fund`, in the smallest unit of the
+/// native token of each.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Funding {
+    pub(crate) relay: u128,
+    pub(crate) asset_reserve: u128,
+    pub(crate) evm: u128,
+    pub(crate) oracle_consumer: u128,
+}
+
+impl Default for Funding {
+    fn default() -> Self {
+        // 1,000 ROC, KSM and consumer tokens with 12 decimals, 1,000 GLMR with 18 decimals
+        Self {
+            relay: 1_000 * 10u128.pow(12),
+            asset_reserve: 1_000 * 10u128.pow(12),
+            evm: 1_000 * 10u128.pow(18),
+            oracle_consumer: 1_000 * 10u128.pow(12),
+        }
+    }
+}
+
+impl Keyring {
+    pub(crate) const ALL: [Keyring; 4] = [
+        Keyring::Alice,
+        Keyring::Bob,
+        Keyring::Charlie,
+        Keyring::Dave,
+    ];
+
+    /// The name of the actor's sr25519 account, which is also its seed.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Keyring::Alice => accounts::ALICE,
+            Keyring::Bob => accounts::BOB,
+            Keyring::Charlie => accounts::CHARLIE,
+            Keyring::Dave => accounts::DAVE,
+        }
+    }
+
+    /// The name of the actor's evm address, as per the moonbeam dev accounts.
+    pub(crate) fn evm_name(self) -> &'static str {
+        match self {
+            Keyring::Alice => "Alith",
+            Keyring::Bob => "Balthazar",
+            Keyring::Charlie => "Charleth",
+            Keyring::Dave => "Dorothy",
+        }
+    }
+
+    pub(crate) fn account(self) -> AccountId32 {
+        OracleConsumerParachain::account_id_of(self.name())
+    }
+
+    pub(crate) fn address(self) -> Address {
+        match self {
+            Keyring::Alice => *evm::ALITH,
+            Keyring::Bob => *evm::BALTHAZAR,
+            Keyring::Charlie => *evm::CHARLETH,
+            Keyring::Dave => *evm::DOROTHY,
+        }
+    }
+
+    /// The signer of the actor's sr25519 account.
+    pub(crate) fn pair(self) -> sr25519::Pair {
+        sr25519::Pair::from_string(&format!("//{}", self.name()), None)
+            .expect("static values are valid; qed")
+    }
+
+    /// The signer of the actor's evm address.
+    pub(crate) fn evm_pair(self) -> ecdsa::Pair {
+        // https://github.com/moonbeam-foundation/moonbeam#prefunded-development-addresses
+        let private_key = match self {
+            Keyring::Alice => "5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133",
+            Keyring::Bob => "8075991ce870b93a8870eca0c0f91913d12f47948ca0fd25b49c6fa7cdbeee8b",
+            Keyring::Charlie => "0b6e18cafb6ed99687ec547bd28139cafdd2bffe70e6b688025de6b445aa5c5b",
+            Keyring::Dave => "39539ab1876910bbf3a223d84a29e28f1cb4e2e456503e7e91ed39b2e7223d68",
+        };
+        ecdsa::Pair::from_seed_slice(&sp_core::bytes::from_hex(private_key).unwrap())
+            .expect("static values are valid; qed")
+    }
+
+    /// Looks up an actor by either of its names, ignoring case.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|actor| {
+            actor.name().eq_ignore_ascii_case(name) || actor.evm_name().eq_ignore_ascii_case(name)
+        })
+    }
+
+    pub(crate) fn from_account(account: &AccountId32) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|actor| actor.account() == *account)
+    }
+
+    pub(crate) fn from_address(address: Address) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|actor| actor.address() == address)
+    }
+
+    /// Mints the default funding to the actor on every chain. Must not be called within
+    /// `execute_with`.
+    pub(crate) fn fund(self) {
+        self.fund_with(Funding::default())
+    }
+
+    /// Mints the funding to the actor on every chain, including the second oracle consumer
+    /// parachain. Must not be called within `execute_with`.
+    pub(crate) fn fund_with(self, funding: Funding) {
+        let account = self.account();
+        Rococo::execute_with(|| {
+            assert_ok!(rococo_runtime::Balances::mint_into(&account, funding.relay));
+        });
+        AssetReserveParachain::execute_with(|| {
+            assert_ok!(statemine_runtime::Balances::mint_into(
+                &account,
+                funding.asset_reserve
+            ));
+        });
+        EvmParachain::execute_with(|| {
+            assert_ok!(moonbeam_runtime::Balances::mint_into(
+                &self.address().into(),
+                funding.evm
+            ));
+        });
+        OracleConsumerParachain::execute_with(|| {
+            assert_ok!(oracle_consumer_runtime::Balances::mint_into(
+                &account,
+                funding.oracle_consumer
+            ));
+        });
+        SecondOracleConsumerParachain::execute_with(|| {
+            assert_ok!(oracle_consumer_runtime::Balances::mint_into(
+                &account,
+                funding.oracle_consumer
+            ));
+        });
+    }
+
+    /// Labels the accounts and addresses of known actors within debug formatted text, such as
+    /// events, with the name of the actor, e.g. `Bob(5FHneW46...)`.
+    pub(crate) fn annotate(text: &str) -> String {
+        Self::ALL.into_iter().fold(text.to_string(), |text, actor| {
+            let account = actor.account().to_string();
+            let address = format!("{:?}", actor.address());
+            text.replace(&account, &format!("{}({account})", actor.name()))
+                .replace(&address, &format!("{}({address})", actor.evm_name()))
+        })
+    }
+}
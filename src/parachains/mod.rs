@@ -2,5 +2,33 @@ use super::*;
 use lazy_static::lazy_static;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+pub(crate) mod asset_reserve;
+pub(crate) mod balances;
+pub(crate) mod data_feed;
+pub(crate) mod dispute;
 pub(crate) mod evm;
+pub(crate) mod invariants;
+pub(crate) mod keyring;
 pub(crate) mod oracle_consumer;
+pub(crate) mod reporter;
+pub(crate) mod scenario;
+#[cfg(test)]
+pub(crate) mod sequence;
+
+/// The maximum size of an hrmp message, as per the host configuration of polkadot and kusama,
+/// which is lower than the channel limit used by the emulator.
+pub(crate) const MAX_HRMP_MESSAGE_SIZE: usize = 100 * 1024;
+
+/// The size of each xcmp page queued for sending by the current parachain. Pages are only sent at
+/// the end of `execute_with`, so must be read within it after sending.
+pub(crate) fn outbound_xcmp_page_sizes() -> Vec<usize> {
+    // the evm parachain uses a fork of the xcmp queue pallet which cannot be named, so storage is
+    // read directly
+    #[allow(deprecated)]
+    frame_support::storage::migration::storage_iter::<Vec<u8>>(
+        b"XcmpQueue",
+        b"OutboundXcmpMessages",
+    )
+    .map(|(_, page)| page.len())
+    .collect()
+}
@@ -4,6 +4,7 @@ use ethabi::ethereum_types::H256;
 use frame_support::{
     assert_ok,
     traits::{fungible::Inspect, UnixTime},
+    weights::Weight,
     BoundedVec,
 };
 use integration_tests_common::constants::accounts;
@@ -24,9 +25,26 @@ lazy_static! {
     pub(crate) static ref DAVE: AccountId = OracleConsumerParachain::account_id_of(accounts::DAVE);
 }
 
+/// The id of the oracle consumer parachain.
+pub(crate) const PARA_ID: u32 = 3_000;
+/// The id of the second oracle consumer parachain, using the same runtime and evm contracts.
+pub(crate) const SECOND_PARA_ID: u32 = 3_001;
+
+/// Environment variable pointing at a raw chain spec or key/value dump of the oracle consumer
+/// parachain, used as its genesis instead of the genesis config below.
+pub(crate) const GENESIS_FILE: &str = "ORACLE_CONSUMER_PARACHAIN_GENESIS";
+
 pub(crate) fn genesis() -> Storage {
-    const PARA_ID: ParaId = ParaId::new(3_000);
+    crate::chain_spec::genesis_from_file(GENESIS_FILE, ParaId::new(PARA_ID))
+        .unwrap_or_else(|| genesis_for(ParaId::new(PARA_ID)))
+}
+
+pub(crate) fn second_genesis() -> Storage {
+    genesis_for(ParaId::new(SECOND_PARA_ID))
+}
 
+// the tellor pallet reads its parachain id from parachain info, so only the genesis differs
+fn genesis_for(para_id: ParaId) -> Storage {
     let pallet_id =
         <<OracleConsumerParachain as Parachain>::Runtime as tellor::Config>::PalletId::get();
 
@@ -77,7 +95,7 @@ pub(crate) fn genesis() -> Storage {
             ..Default::default()
         },
         parachain_info: ParachainInfoConfig {
-            parachain_id: PARA_ID,
+            parachain_id: para_id,
             ..Default::default()
         },
         session: SessionConfig {
@@ -110,6 +128,167 @@ pub(crate) fn init() {
     );
 }
 
+/// The account of the tellor pallet, used for paying fees.
+pub(crate) fn pallet_account() -> AccountId {
+    <Runtime as tellor::Config>::PalletId::get().into_account_truncating()
+}
+
+/// Sets the free balance of an account, allowing accounts funded at genesis to be drained or
+/// topped up.
+pub(crate) fn set_balance(who: AccountId, amount: Balance) {
+    assert_ok!(Balances::force_set_balance(
+        RuntimeOrigin::root(),
+        who.clone().into(),
+        amount
+    ));
+    assert_eq!(Balances::free_balance(who), amount);
+}
+
+/// Claims assets trapped on the evm parachain when a message sent by the tellor pallet failed after
+/// its fees were withdrawn, depositing them back into the pallet derivative account.
+pub(crate) fn claim_trapped_assets(assets: MultiAssets) {
+    let fees = assets.get(0).expect("assets to claim").clone();
+    send_to_evm_parachain(Xcm(vec![
+        ClaimAsset {
+            assets,
+            ticket: Here.into(),
+        },
+        BuyExecution {
+            fees,
+            weight_limit: Unlimited,
+        },
+        DepositAsset {
+            assets: Wild(AllCounted(1)),
+            beneficiary: MultiLocation::new(
+                0,
+                X1(AccountKey20 {
+                    network: None,
+                    key: evm::PALLET_DERIVATIVE_ACCOUNT.0,
+                }),
+            ),
+        },
+    ]));
+}
+
+/// Sends a message to the evm parachain as the tellor pallet, so that fees are withdrawn from the
+/// pallet derivative account, as with messages sent by the pallet itself.
+pub(crate) fn send_to_evm_parachain(message: Xcm<()>) {
+    use frame_support::traits::PalletInfoAccess;
+    use oracle_consumer_runtime::PolkadotXcm;
+    let dest = MultiLocation::new(1, X1(Parachain(EvmParachain::para_id().into())));
+    let mut instructions = vec![DescendOrigin(X1(PalletInstance(
+        <Tellor as PalletInfoAccess>::index() as u8,
+    )))];
+    instructions.extend(message.0);
+    assert_ok!(PolkadotXcm::send(
+        RuntimeOrigin::root(),
+        Box::new(VersionedMultiLocation::V3(dest)),
+        Box::new(VersionedXcm::V3(Xcm(instructions)))
+    ));
+}
+
+/// Captures the messages which the call would send to other parachains, without sending them or
+/// persisting any other changes, such as to resend a tellor message with altered fees.
+pub(crate) fn capture_xcm(call: impl FnOnce()) -> Vec<Xcm<()>> {
+    use codec::Decode;
+    use cumulus_primitives_core::XcmpMessageFormat;
+    use frame_support::storage::{with_transaction, TransactionOutcome};
+    with_transaction(|| {
+        call();
+        // pages are read directly, as per `outbound_xcmp_page_sizes`
+        #[allow(deprecated)]
+        let messages = frame_support::storage::migration::storage_iter::<Vec<u8>>(
+            b"XcmpQueue",
+            b"OutboundXcmpMessages",
+        )
+        .flat_map(|(_, page)| {
+            let mut data = page.as_slice();
+            assert_eq!(
+                XcmpMessageFormat::decode(&mut data),
+                Ok(XcmpMessageFormat::ConcatenatedVersionedXcm)
+            );
+            let mut messages = Vec::new();
+            while !data.is_empty() {
+                let message = VersionedXcm::<()>::decode(&mut data)
+                    .expect("xcmp page contains versioned xcm; qed");
+                messages.push(message.try_into().expect("message is v3; qed"));
+            }
+            messages
+        })
+        .collect();
+        TransactionOutcome::Rollback(Ok::<_, sp_runtime::DispatchError>(messages))
+    })
+    .expect("messages captured")
+}
+
+/// The account on the oracle consumer parachain derived from a contract on the evm parachain,
+/// which pays the xcm fees of the reports sent by the contract.
+pub(crate) fn contract_account(contract: Address) -> AccountId {
+    use oracle_consumer_runtime::xcm_config::LocationToAccountId;
+    let location = MultiLocation::new(
+        1,
+        X2(
+            Parachain(EvmParachain::para_id().into()),
+            AccountKey20 {
+                network: None,
+                key: contract.0,
+            },
+        ),
+    );
+    <LocationToAccountId as Convert<MultiLocation, AccountId>>::convert(location)
+        .expect("location converts to account; qed")
+}
+
+/// The fee charged in the native token for purchasing the weight, using the runtime's xcm trader.
+pub(crate) fn native_fee(weight: Weight) -> Balance {
+    use frame_support::storage::{with_transaction, TransactionOutcome};
+    use oracle_consumer_runtime::xcm_config::XcmConfig;
+    use xcm_executor::{traits::WeightTrader, Assets};
+    const PAYMENT: Balance = Balance::MAX / 2;
+    // the trader deposits the fee when dropped, so changes are rolled back
+    with_transaction(|| {
+        let mut trader = <XcmConfig as xcm_executor::Config>::Trader::new();
+        let unused = trader
+            .buy_weight(
+                weight,
+                Assets::from(MultiAsset::from((MultiLocation::here(), PAYMENT))),
+            )
+            .expect("weight purchased");
+        let fee = PAYMENT
+            - unused
+                .fungible
+                .get(&Concrete(MultiLocation::here()))
+                .copied()
+                .unwrap_or_default();
+        TransactionOutcome::Rollback(Ok::<_, sp_runtime::DispatchError>(fee))
+    })
+    .expect("fee calculated")
+}
+
+/// The weight of each xcm message from a sibling parachain successfully executed since the event
+/// with the given index.
+pub(crate) fn executed_xcm_weights(since: usize) -> Vec<Weight> {
+    use codec::{Decode, Encode};
+    use oracle_consumer_runtime::RuntimeEvent;
+    System::events()
+        .into_iter()
+        .skip(since)
+        .filter_map(|r| match r.event {
+            // decoded as per the evm parachain, as the `Success { message_hash, weight }` event
+            // (index 0) of the xcmp queue pallet is encoded alike
+            RuntimeEvent::XcmpQueue(event) => match event.encode().split_first() {
+                Some((0, mut data)) => Some(
+                    <(Option<[u8; 32]>, Weight)>::decode(&mut data)
+                        .expect("xcmp queue success event is valid; qed")
+                        .1,
+                ),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
 pub(crate) fn feed_id(
     query_id: H256,
     reward: Balance,
@@ -132,18 +311,22 @@ pub(crate) fn feed_id(
 }
 
 pub(crate) fn register(evm_para_id: impl Into<u32>) {
-    use tellor::{weights::WeightInfo, MAX_VOTE_ROUNDS};
     assert_ok!(Tellor::register(RuntimeOrigin::root(), None));
-    let weights = tellor::Weights {
-        report_stake_deposited: <() as WeightInfo>::report_stake_deposited().ref_time(),
-        report_staking_withdraw_request: <() as WeightInfo>::report_staking_withdraw_request()
-            .ref_time(),
-        report_stake_withdrawn: <() as WeightInfo>::report_stake_withdrawn().ref_time(),
-        report_vote_tallied: <() as WeightInfo>::report_vote_tallied().ref_time(),
-        report_vote_executed: <() as WeightInfo>::report_vote_executed(MAX_VOTE_ROUNDS.into())
-            .ref_time(),
-        report_slash: <() as WeightInfo>::report_slash().ref_time(),
-    };
+    System::assert_has_event(
+        tellor::Event::RegistrationSent {
+            para_id: evm_para_id.into(),
+            contract_address: *evm::contracts::registry::REGISTRY_CONTRACT_ADDRESS,
+            weights: default_weights(),
+        }
+        .into(),
+    );
+}
+
+pub(crate) fn register_with_weights(evm_para_id: impl Into<u32>, weights: tellor::Weights) {
+    assert_ok!(Tellor::register(
+        RuntimeOrigin::root(),
+        Some(weights.clone())
+    ));
     System::assert_has_event(
         tellor::Event::RegistrationSent {
             para_id: evm_para_id.into(),
@@ -154,6 +337,21 @@ pub(crate) fn register(evm_para_id: impl Into<u32>) {
     );
 }
 
+/// The weights sent with a registration when none are specified.
+pub(crate) fn default_weights() -> tellor::Weights {
+    use tellor::{weights::WeightInfo, MAX_VOTE_ROUNDS};
+    tellor::Weights {
+        report_stake_deposited: <() as WeightInfo>::report_stake_deposited().ref_time(),
+        report_staking_withdraw_request: <() as WeightInfo>::report_staking_withdraw_request()
+            .ref_time(),
+        report_stake_withdrawn: <() as WeightInfo>::report_stake_withdrawn().ref_time(),
+        report_vote_tallied: <() as WeightInfo>::report_vote_tallied().ref_time(),
+        report_vote_executed: <() as WeightInfo>::report_vote_executed(MAX_VOTE_ROUNDS.into())
+            .ref_time(),
+        report_slash: <() as WeightInfo>::report_slash().ref_time(),
+    }
+}
+
 pub(crate) fn submit_value(
     reporter: AccountId32,
     query_data: &[u8],
@@ -165,11 +363,14 @@ pub(crate) fn submit_value(
     let query_data: QueryData = query_data.to_vec().try_into().unwrap();
     let query_id = Keccak256::hash(query_data.as_slice());
     let value: Value = value.to_vec().try_into().unwrap();
-    let nonce = 0;
+    // nonce is the number of values already reported for the query id
+    let nonce = Tellor::get_new_value_count_by_query_id(query_id)
+        .try_into()
+        .unwrap();
     let timestamp = <Timestamp as UnixTime>::now().as_secs();
 
     assert_ok!(Tellor::submit_value(
-        RuntimeOrigin::signed(reporter),
+        RuntimeOrigin::signed(reporter.clone()),
         query_id,
         value.clone(),
         nonce,
@@ -182,13 +383,20 @@ pub(crate) fn submit_value(
             value,
             nonce,
             query_data,
-            reporter: BOB.clone(),
+            reporter,
         }
         .into(),
     );
     (query_id, timestamp)
 }
 
+/// The stake amount of the reporter, as reported to the tellor pallet by the staking contract.
+pub(crate) fn stake_amount(reporter: &AccountId32) -> sp_core::U256 {
+    Tellor::get_staker_info(reporter.clone())
+        .map(|info| info.stake_amount)
+        .unwrap_or_default()
+}
+
 pub(crate) fn advance_time(time_in_secs: u64) {
     let now = <Timestamp as UnixTime>::now();
     pallet_timestamp::Now::<Runtime>::set(
@@ -0,0 +1,91 @@
+use super::*;
+use evm::contracts::{erc20, staking};
+use frame_support::traits::UnixTime;
+use oracle_consumer_runtime::{Runtime, Tellor, Timestamp};
+use sp_runtime::{app_crypto::ByteArray, AccountId32};
+
+/// A reporter, staking xcTRB on the evm parachain with its staker address and reporting values to
+/// the oracle consumer parachain with its account.
+#[derive(Clone, Debug)]
+pub(crate) struct Reporter {
+    pub(crate) account: AccountId32,
+    pub(crate) staker: Address,
+    last_report: Option<tellor::Timestamp>,
+}
+
+impl From<keyring::Keyring> for Reporter {
+    /// A reporter for the actor, staking with its evm address for its account.
+    fn from(actor: keyring::Keyring) -> Self {
+        Self::new(actor.account(), actor.address())
+    }
+}
+
+impl Reporter {
+    pub(crate) fn new(account: AccountId32, staker: Address) -> Self {
+        Self {
+            account,
+            staker,
+            last_report: None,
+        }
+    }
+
+    /// Mints, approves and stakes xcTRB for the oracle consumer parachain. Must be executed on the
+    /// evm parachain.
+    pub(crate) fn stake(&self, amount: u128) {
+        staking::mint(*evm::XCTRB_ASSET_ID, self.staker, amount);
+        erc20::approve(self.staker, *staking::STAKING_CONTRACT_ADDRESS, amount);
+        staking::deposit_parachain_stake(
+            self.staker,
+            OracleConsumerParachain::para_id(),
+            self.account.to_raw_vec(),
+            amount,
+        );
+        staking::assert_new_parachain_staker_event(
+            OracleConsumerParachain::para_id(),
+            self.staker,
+            self.account.to_raw_vec(),
+            amount,
+        );
+    }
+
+    /// Submits a value using the next nonce for the query id, first advancing time on both
+    /// parachains past the reporting lock of any previous report and any value reported at the
+    /// current time, so that their clocks remain in sync. Must not be called within `execute_with`.
+    pub(crate) fn submit_value(
+        &mut self,
+        query_data: &[u8],
+        value: &[u8],
+    ) -> (tellor::QueryId, tellor::Timestamp) {
+        let wait = OracleConsumerParachain::execute_with(|| {
+            let now = <Timestamp as UnixTime>::now().as_secs();
+            let unlocked = self
+                .last_report
+                .map(|last_report| last_report + <Runtime as tellor::Config>::ReportingLock::get())
+                .filter(|unlocked| now <= *unlocked);
+            match unlocked {
+                Some(unlocked) => unlocked + 1 - now,
+                // values are keyed by timestamp, so only one can be reported per second
+                None if Tellor::time_of_last_new_value() == Some(now) => 1,
+                None => 0,
+            }
+        });
+        if wait > 0 {
+            dispute::advance_time(wait);
+        }
+        OracleConsumerParachain::execute_with(|| self.submit_value_now(query_data, value))
+    }
+
+    /// Submits a value at the current time using the next nonce for the query id, which must be
+    /// after the reporting lock of any previous report, such as when time is controlled by a data
+    /// feed. Must be executed on the oracle consumer parachain.
+    pub(crate) fn submit_value_now(
+        &mut self,
+        query_data: &[u8],
+        value: &[u8],
+    ) -> (tellor::QueryId, tellor::Timestamp) {
+        let (query_id, timestamp) =
+            oracle_consumer::submit_value(self.account.clone(), query_data, value);
+        self.last_report = Some(timestamp);
+        (query_id, timestamp)
+    }
+}
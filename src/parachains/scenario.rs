@@ -0,0 +1,243 @@
+use super::*;
+use crate::query::Query;
+use dispute::Dispute;
+use evm::contracts::{governance, registry, staking};
+use frame_support::{assert_ok, traits::Get, BoundedVec};
+use oracle_consumer_runtime::{AccountId, Balance, Runtime, RuntimeOrigin, System, Tellor};
+use reporter::Reporter;
+use sp_core::H256;
+use std::collections::BTreeMap;
+use tellor::VoteResult;
+
+/// A fluent builder for cross-chain flows between the oracle consumer and evm parachains. Each
+/// step executes on the relevant parachains, with messages routed by the emulator at the end of
+/// each `execute_with`, and asserts the expected events on both sides. Steps must therefore not be
+/// called within `execute_with`.
+#[derive(Debug, Default)]
+pub(crate) struct Scenario {
+    reporters: Vec<Reporter>,
+    // the query id and timestamp of the last reported value
+    last_report: Option<(H256, tellor::Timestamp)>,
+    dispute: Option<Dispute>,
+    // the beneficiary of the dispute fees on the evm parachain
+    beneficiary: Option<Address>,
+    result: Option<VoteResult>,
+    // the total tipped and the number of values reported by each account, which weight their votes
+    tips: BTreeMap<AccountId, Balance>,
+    reports: BTreeMap<AccountId, u128>,
+    // the time elapsed since the current vote round began
+    elapsed: u64,
+    // the tallies of votes cast on the oracle consumer parachain in the current round which have
+    // yet to be sent to the evm parachain, as (users, reporters) each of (for, against, invalid)
+    pending_votes: Option<([Balance; 3], [u128; 3])>,
+}
+
+impl Scenario {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the xcTRB asset and deploys and initialises the contracts on the evm parachain.
+    pub(crate) fn deploy(self) -> Self {
+        EvmParachain::execute_with(|| {
+            evm::create_xctrb_asset();
+            registry::deploy();
+            staking::deploy(*registry::REGISTRY_CONTRACT_ADDRESS, *evm::XCTRB_ADDRESS);
+            governance::deploy(*registry::REGISTRY_CONTRACT_ADDRESS, *evm::ALITH);
+            staking::init(*governance::GOVERNANCE_CONTRACT_ADDRESS);
+            governance::init(*staking::STAKING_CONTRACT_ADDRESS);
+        });
+        self
+    }
+
+    /// Registers the oracle consumer parachain with the contracts on the evm parachain.
+    pub(crate) fn register(self) -> Self {
+        OracleConsumerParachain::execute_with(
+            || oracle_consumer::register(EvmParachain::para_id()),
+        );
+        EvmParachain::execute_with(|| {
+            registry::assert_executed(*evm::PALLET_DERIVATIVE_ACCOUNT);
+            registry::assert_parachain_registered_event(
+                *evm::PALLET_DERIVATIVE_ACCOUNT,
+                OracleConsumerParachain::para_id(),
+                *evm::PALLET_DERIVATIVE_ACCOUNT,
+            );
+        });
+        self
+    }
+
+    /// Stakes the amount from the staker address on the evm parachain for the account on the
+    /// oracle consumer parachain, which then becomes the current reporter.
+    pub(crate) fn stake(mut self, staker: Address, account: AccountId, amount: Balance) -> Self {
+        let reporter = Reporter::new(account.clone(), staker);
+        EvmParachain::execute_with(|| reporter.stake(amount));
+        OracleConsumerParachain::execute_with(|| {
+            System::assert_has_event(
+                tellor::Event::NewStakerReported {
+                    staker: account,
+                    amount: amount.into(),
+                    address: staker,
+                }
+                .into(),
+            );
+        });
+        self.reporters.push(reporter);
+        self
+    }
+
+    /// Reports a value for the query using the current reporter.
+    pub(crate) fn report(mut self, query: &Query, value: &[u8]) -> Self {
+        let reporter = self
+            .reporters
+            .last_mut()
+            .expect("a reporter must first be staked");
+        let (query_id, timestamp) = reporter.submit_value(&query.query_data(), value);
+        let account = reporter.account.clone();
+        OracleConsumerParachain::execute_with(|| {
+            let nonce = Tellor::get_new_value_count_by_query_id(query_id) - 1;
+            System::assert_has_event(
+                tellor::Event::NewReport {
+                    query_id,
+                    time: timestamp,
+                    value: value.to_vec().try_into().unwrap(),
+                    nonce: nonce.try_into().unwrap(),
+                    query_data: query.query_data().try_into().unwrap(),
+                    reporter: account.clone(),
+                }
+                .into(),
+            );
+            assert_eq!(Tellor::time_of_last_new_value(), Some(timestamp));
+        });
+        *self.reports.entry(account).or_default() += 1;
+        self.last_report = Some((query_id, timestamp));
+        self
+    }
+
+    /// Tips the query on the oracle consumer parachain.
+    pub(crate) fn tip(mut self, tipper: AccountId, query: &Query, amount: Balance) -> Self {
+        OracleConsumerParachain::execute_with(|| {
+            let query_data: BoundedVec<u8, <Runtime as tellor::Config>::MaxQueryDataLength> =
+                query.query_data().try_into().unwrap();
+            assert_ok!(Tellor::tip(
+                RuntimeOrigin::signed(tipper.clone()),
+                query.query_id(),
+                amount,
+                query_data.clone()
+            ));
+            System::assert_has_event(
+                tellor::Event::TipAdded {
+                    query_id: query.query_id(),
+                    amount,
+                    query_data,
+                    tipper: tipper.clone(),
+                }
+                .into(),
+            );
+        });
+        *self.tips.entry(tipper).or_default() += amount;
+        self
+    }
+
+    /// Disputes the last reported value, with the dispute fees paid to the beneficiary on the evm
+    /// parachain should the dispute pass. The reporter is slashed on the first round, with any
+    /// further call beginning the next round of the same dispute, which must be by the original
+    /// initiator.
+    pub(crate) fn dispute(mut self, initiator: AccountId, beneficiary: Address) -> Self {
+        if let Some(dispute) = self.dispute.as_mut() {
+            assert_eq!(
+                initiator, dispute.initiator,
+                "next round of dispute must be begun by its initiator"
+            );
+            dispute.begin_next_round(beneficiary);
+        } else {
+            let (query_id, timestamp) = self.last_report();
+            self.dispute = Some(Dispute::begin(initiator, beneficiary, query_id, timestamp));
+        }
+        let reporter = self.disputed_reporter().staker;
+        let slash_amount = <Runtime as tellor::Config>::MinimumStakeAmount::get();
+        self.current_dispute()
+            .assert_begun_on_evm_parachain(reporter, slash_amount);
+        self.beneficiary = Some(beneficiary);
+        self.result = None;
+        self.elapsed = 0;
+        self.pending_votes = None;
+        self
+    }
+
+    /// Votes on the current dispute round on the oracle consumer parachain, with each vote weighted
+    /// by the tips and reports of the voter within the scenario. The votes are asserted as received
+    /// by the evm parachain once time advances past the parachain voting cut-off.
+    pub(crate) fn vote(mut self, votes: &[(AccountId, Option<bool>)]) -> Self {
+        self.current_dispute().vote(votes);
+        let (users, reporters) = self.pending_votes.get_or_insert_with(Default::default);
+        for (voter, supports) in votes {
+            let index = match supports {
+                Some(true) => 0,
+                Some(false) => 1,
+                None => 2,
+            };
+            users[index] += self.tips.get(voter).copied().unwrap_or_default();
+            reporters[index] += self.reports.get(voter).copied().unwrap_or_default();
+        }
+        self
+    }
+
+    /// Advances time on both parachains, sending any votes via the `on_initialize` hook and
+    /// asserting they were received by the evm parachain once past the parachain voting cut-off.
+    pub(crate) fn advance(mut self, time_in_secs: u64) -> Self {
+        dispute::advance_time(time_in_secs);
+        self.elapsed += time_in_secs;
+        if self.elapsed <= dispute::PARACHAIN_VOTING_CUT_OFF {
+            return self;
+        }
+        if let Some((users, reporters)) = self.pending_votes.take() {
+            let id = self.current_dispute().id;
+            EvmParachain::execute_with(|| {
+                governance::assert_parachain_voted_event(
+                    id,
+                    users[0],
+                    users[1],
+                    users[2],
+                    reporters[0],
+                    reporters[1],
+                    reporters[2],
+                );
+            });
+        }
+        self
+    }
+
+    /// Tallies votes for the current dispute round on the evm parachain, asserting the result.
+    pub(crate) fn tally(mut self, result: VoteResult) -> Self {
+        let beneficiary = self.beneficiary.expect("a dispute must first be begun");
+        let reporter = self.disputed_reporter().staker;
+        self.current_dispute().tally(result, beneficiary, reporter);
+        self.result = Some(result);
+        self
+    }
+
+    /// Executes the tallied vote on the evm parachain once the tally period has elapsed.
+    pub(crate) fn execute(self) -> Self {
+        let result = self.result.expect("votes must first be tallied");
+        self.current_dispute().execute(result);
+        self
+    }
+
+    /// The query id and timestamp of the last reported value.
+    pub(crate) fn last_report(&self) -> (H256, tellor::Timestamp) {
+        self.last_report.expect("no value reported")
+    }
+
+    pub(crate) fn current_dispute(&self) -> &Dispute {
+        self.dispute.as_ref().expect("no dispute begun")
+    }
+
+    // the reporter of the disputed value
+    fn disputed_reporter(&self) -> &Reporter {
+        let reporter = &self.current_dispute().reporter;
+        self.reporters
+            .iter()
+            .find(|r| &r.account == reporter)
+            .expect("disputed reporter not staked")
+    }
+}
@@ -0,0 +1,322 @@
+use super::*;
+use crate::query::{self, Query};
+use dispute::{Dispute, PARACHAIN_VOTING_CUT_OFF};
+use evm::contracts::{erc20, governance::GOVERNANCE_CONTRACT_ADDRESS, staking};
+use frame_support::{assert_ok, traits::Get, traits::UnixTime};
+use invariants::InvariantChecker;
+use keyring::Keyring;
+use oracle_consumer_runtime::{AccountId, Balance, Runtime, RuntimeOrigin, Tellor};
+use proptest::prelude::*;
+use reporter::Reporter;
+use scenario::Scenario;
+use sp_core::H256;
+use tellor::{DAYS, HOURS};
+
+/// The number of reporters, stakers and queries operations are generated for.
+const REPORTERS: usize = 2;
+const QUERIES: usize = 2;
+/// The time after a stake deposit or withdrawal request before locked stake can be withdrawn.
+const WITHDRAWAL_LOCK: u64 = 7 * DAYS;
+/// The time after a report within which it can be disputed.
+const DISPUTE_WINDOW: u64 = 12 * HOURS;
+
+/// An operation within a randomly generated sequence. Indices refer to reporters, queries, reports
+/// or voters modulo their number, so that shrunk sequences remain meaningful. Operations which
+/// would be rejected in the current state are skipped.
+#[derive(Clone, Debug)]
+pub(crate) enum Operation {
+    /// Deposits a stake, in quarters of the minimum stake amount.
+    Stake {
+        reporter: usize,
+        quarters: u8,
+    },
+    /// Requests a withdrawal of stake, in quarters of the minimum stake amount.
+    RequestWithdraw {
+        reporter: usize,
+        quarters: u8,
+    },
+    Withdraw {
+        reporter: usize,
+    },
+    Report {
+        reporter: usize,
+        query: usize,
+    },
+    Tip {
+        query: usize,
+        amount: u16,
+    },
+    Dispute {
+        report: usize,
+    },
+    Vote {
+        voter: usize,
+        supports: Option<bool>,
+    },
+    Advance {
+        hours: u8,
+    },
+}
+
+/// Generates sequences of up to `max` operations.
+pub(crate) fn operations(max: usize) -> impl Strategy<Value = Vec<Operation>> {
+    let reporter = 0..REPORTERS;
+    let operation = prop_oneof![
+        3 => (reporter.clone(), 1..=8u8)
+            .prop_map(|(reporter, quarters)| Operation::Stake { reporter, quarters }),
+        1 => (reporter.clone(), 1..=8u8)
+            .prop_map(|(reporter, quarters)| Operation::RequestWithdraw { reporter, quarters }),
+        1 => reporter.clone().prop_map(|reporter| Operation::Withdraw { reporter }),
+        3 => (reporter, 0..QUERIES).prop_map(|(reporter, query)| Operation::Report { reporter, query }),
+        1 => (0..QUERIES, 1..=1_000u16).prop_map(|(query, amount)| Operation::Tip { query, amount }),
+        2 => any::<usize>().prop_map(|report| Operation::Dispute { report }),
+        2 => (any::<usize>(), any::<Option<bool>>())
+            .prop_map(|(voter, supports)| Operation::Vote { voter, supports }),
+        2 => (1..=48u8).prop_map(|hours| Operation::Advance { hours }),
+    ];
+    prop::collection::vec(operation, 1..=max)
+}
+
+// the expected stake of a staker on the evm parachain
+#[derive(Clone, Debug, Default)]
+struct Stake {
+    staked: Balance,
+    locked: Balance,
+    start_date: u64,
+}
+
+#[derive(Clone, Debug)]
+struct Report {
+    query_id: H256,
+    timestamp: u64,
+    reporter: usize,
+    disputed: bool,
+}
+
+/// Runs operations against a fresh network with the contracts deployed and the oracle consumer
+/// parachain registered, checking that both chains agree on stakes and slashes after every step.
+pub(crate) struct Sequence {
+    reporters: Vec<Reporter>,
+    stakes: Vec<Stake>,
+    reports: Vec<Report>,
+    // the latest dispute, the time it was begun and the accounts which have voted on it
+    dispute: Option<(Dispute, u64, Vec<AccountId>)>,
+    slashed: Balance,
+    checker: InvariantChecker,
+    // the difference between the clocks of the oracle consumer and evm parachains, set when each
+    // is initialised, which every operation must preserve
+    skew: i64,
+}
+
+impl Sequence {
+    pub(crate) fn new() -> Self {
+        RococoMockNet::reset();
+        Scenario::new().deploy().register();
+        let reporters = vec![
+            Reporter::from(Keyring::Bob),
+            Reporter::from(Keyring::Charlie),
+        ];
+        let mut checker = InvariantChecker::new();
+        for reporter in &reporters {
+            checker.add_staker(reporter.account.clone(), reporter.staker);
+        }
+        Self {
+            reporters,
+            stakes: vec![Stake::default(); REPORTERS],
+            reports: Vec::new(),
+            dispute: None,
+            slashed: 0,
+            checker,
+            skew: dispute::clock_skew(),
+        }
+    }
+
+    /// Runs the operations, checking invariants after each, returning whether each operation was
+    /// applied or skipped.
+    pub(crate) fn run(mut self, operations: &[Operation]) -> Vec<bool> {
+        let mut applied = Vec::with_capacity(operations.len());
+        for (index, operation) in operations.iter().enumerate() {
+            applied.push(self.apply(operation));
+            tracing::debug!(target: "sequence", "{index}: {operation:?} applied: {}", applied[index]);
+            self.check_invariants();
+        }
+        applied
+    }
+
+    // applies the operation, returning whether it was applicable to the current state
+    fn apply(&mut self, operation: &Operation) -> bool {
+        let minimum_stake = <Runtime as tellor::Config>::MinimumStakeAmount::get();
+        match *operation {
+            Operation::Stake { reporter, quarters } => {
+                let amount = minimum_stake * quarters as Balance / 4;
+                EvmParachain::execute_with(|| self.reporters[reporter].stake(amount));
+                let stake = &mut self.stakes[reporter];
+                // locked stake is restaked before any further tokens are transferred
+                stake.locked = stake.locked.saturating_sub(amount);
+                stake.staked += amount;
+                stake.start_date = evm_now();
+            }
+            Operation::RequestWithdraw { reporter, quarters } => {
+                let amount = minimum_stake * quarters as Balance / 4;
+                let stake = &mut self.stakes[reporter];
+                if stake.staked < amount {
+                    return false;
+                }
+                EvmParachain::execute_with(|| {
+                    staking::request_parachain_stake_withdraw(
+                        self.reporters[reporter].staker,
+                        OracleConsumerParachain::para_id(),
+                        amount,
+                    )
+                });
+                stake.staked -= amount;
+                stake.locked += amount;
+                stake.start_date = evm_now();
+            }
+            Operation::Withdraw { reporter } => {
+                let stake = &mut self.stakes[reporter];
+                if stake.locked == 0 || evm_now() < stake.start_date + WITHDRAWAL_LOCK {
+                    return false;
+                }
+                EvmParachain::execute_with(|| {
+                    staking::withdraw_parachain_stake(
+                        self.reporters[reporter].staker,
+                        OracleConsumerParachain::para_id(),
+                    )
+                });
+                stake.locked = 0;
+            }
+            Operation::Report { reporter, query } => {
+                if self.stakes[reporter].staked < minimum_stake {
+                    return false;
+                }
+                let query = query_at(query);
+                let value = query::encode_price(&self.reports.len().to_string());
+                let (query_id, timestamp) =
+                    self.reporters[reporter].submit_value(&query.query_data(), &value);
+                self.reports.push(Report {
+                    query_id,
+                    timestamp,
+                    reporter,
+                    disputed: false,
+                });
+            }
+            Operation::Tip { query, amount } => {
+                let query = query_at(query);
+                OracleConsumerParachain::execute_with(|| {
+                    assert_ok!(Tellor::tip(
+                        RuntimeOrigin::signed(Keyring::Dave.account()),
+                        query.query_id(),
+                        amount.into(),
+                        query.query_data().try_into().unwrap()
+                    ));
+                });
+            }
+            Operation::Dispute { report } => {
+                if self.reports.is_empty() {
+                    return false;
+                }
+                let index = report % self.reports.len();
+                let report = &self.reports[index];
+                let stake = &self.stakes[report.reporter];
+                let now = consumer_now();
+                if report.disputed
+                    || now >= report.timestamp + DISPUTE_WINDOW
+                    || stake.staked + stake.locked == 0
+                {
+                    return false;
+                }
+                let dispute = Dispute::begin(
+                    Keyring::Dave.account(),
+                    Keyring::Dave.address(),
+                    report.query_id,
+                    report.timestamp,
+                );
+                // locked stake is slashed before staked
+                let slash = minimum_stake.min(stake.staked + stake.locked);
+                dispute
+                    .assert_begun_on_evm_parachain(self.reporters[report.reporter].staker, slash);
+                let stake = &mut self.stakes[report.reporter];
+                let from_locked = slash.min(stake.locked);
+                stake.locked -= from_locked;
+                stake.staked -= slash - from_locked;
+                self.slashed += slash;
+                self.reports[index].disputed = true;
+                self.checker.add_dispute(dispute.id);
+                self.dispute = Some((dispute, now, Vec::new()));
+            }
+            Operation::Vote { voter, supports } => {
+                let Some((dispute, begun, voters)) = self.dispute.as_mut() else {
+                    return false;
+                };
+                let voters_count = self.reporters.len() + 1;
+                let voter = match voter % voters_count {
+                    i if i < self.reporters.len() => self.reporters[i].account.clone(),
+                    _ => Keyring::Dave.account(),
+                };
+                if voters.contains(&voter) || consumer_now() >= *begun + PARACHAIN_VOTING_CUT_OFF {
+                    return false;
+                }
+                dispute.vote(&[(voter.clone(), supports)]);
+                voters.push(voter);
+            }
+            Operation::Advance { hours } => dispute::advance_time(hours as u64 * HOURS),
+        }
+        true
+    }
+
+    /// Asserts that the stakes and slashes recorded by the contracts match the expected state, and
+    /// that both parachains agree on them and on the time.
+    pub(crate) fn check_invariants(&self) {
+        // time must advance on both parachains, so that time-based checks agree
+        assert_eq!(
+            dispute::clock_skew(),
+            self.skew,
+            "clocks of parachains out of sync"
+        );
+        let para_id = OracleConsumerParachain::para_id();
+        EvmParachain::execute_with(|| {
+            for (reporter, stake) in self.reporters.iter().zip(&self.stakes) {
+                let info = staking::get_parachain_staker_info(para_id, reporter.staker);
+                assert_eq!(
+                    info.staked_balance,
+                    stake.staked.into(),
+                    "staked balance of {reporter:?}"
+                );
+                assert_eq!(
+                    info.locked_balance,
+                    stake.locked.into(),
+                    "locked balance of {reporter:?}"
+                );
+            }
+            let total: Balance = self.stakes.iter().map(|s| s.staked + s.locked).sum();
+            assert_eq!(
+                erc20::balance_of(*staking::STAKING_CONTRACT_ADDRESS),
+                total.into()
+            );
+            assert_eq!(
+                erc20::balance_of(*GOVERNANCE_CONTRACT_ADDRESS),
+                self.slashed.into()
+            );
+        });
+        // stakes and disputes must also agree with the tellor pallet
+        self.checker.assert_consistent();
+    }
+}
+
+fn query_at(index: usize) -> Query {
+    match index % QUERIES {
+        0 => Query::spot_price("eth", "usd"),
+        _ => Query::spot_price("btc", "usd"),
+    }
+}
+
+fn evm_now() -> u64 {
+    EvmParachain::execute_with(|| <moonbeam_runtime::Timestamp as UnixTime>::now().as_secs())
+}
+
+fn consumer_now() -> u64 {
+    OracleConsumerParachain::execute_with(|| {
+        <oracle_consumer_runtime::Timestamp as UnixTime>::now().as_secs()
+    })
+}
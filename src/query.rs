@@ -0,0 +1,153 @@
+use ethabi::{decode, encode, ParamType, Token};
+use sp_core::{H256, U256};
+use sp_runtime::traits::{Hash, Keccak256};
+use tellor::Address;
+
+/// A standard Tellor query type, as per https://github.com/tellor-io/dataSpecs. Query data is the
+/// ABI encoding of the query type name and its encoded parameters, with the query id being the
+/// keccak hash of the query data.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum Query {
+    /// The spot price of an asset in a currency, reported as a uint256 with 18 decimals.
+    SpotPrice { asset: String, currency: String },
+    /// A numeric value parsed from an api response, reported as a uint256 with 18 decimals.
+    NumericApiResponse { url: String, parse_str: String },
+    /// A pseudorandom number for a timestamp, reported as a bytes32.
+    TellorRng { timestamp: u64 },
+    /// The result of a view function call on an evm chain, reported as the returned bytes and the
+    /// timestamp of the block used.
+    EvmCall {
+        chain_id: u64,
+        contract: Address,
+        calldata: Vec<u8>,
+    },
+}
+
+impl Query {
+    pub(crate) fn spot_price(asset: &str, currency: &str) -> Self {
+        Query::SpotPrice {
+            asset: asset.to_string(),
+            currency: currency.to_string(),
+        }
+    }
+
+    pub(crate) fn numeric_api_response(url: &str, parse_str: &str) -> Self {
+        Query::NumericApiResponse {
+            url: url.to_string(),
+            parse_str: parse_str.to_string(),
+        }
+    }
+
+    pub(crate) fn tellor_rng(timestamp: u64) -> Self {
+        Query::TellorRng { timestamp }
+    }
+
+    pub(crate) fn evm_call(chain_id: u64, contract: Address, calldata: Vec<u8>) -> Self {
+        Query::EvmCall {
+            chain_id,
+            contract,
+            calldata,
+        }
+    }
+
+    pub(crate) fn query_type(&self) -> &'static str {
+        match self {
+            Query::SpotPrice { .. } => "SpotPrice",
+            Query::NumericApiResponse { .. } => "NumericApiResponse",
+            Query::TellorRng { .. } => "TellorRNG",
+            Query::EvmCall { .. } => "EVMCall",
+        }
+    }
+
+    pub(crate) fn query_data(&self) -> Vec<u8> {
+        let parameters = match self {
+            Query::SpotPrice { asset, currency } => vec![
+                Token::String(asset.clone()),
+                Token::String(currency.clone()),
+            ],
+            Query::NumericApiResponse { url, parse_str } => {
+                vec![Token::String(url.clone()), Token::String(parse_str.clone())]
+            }
+            Query::TellorRng { timestamp } => vec![Token::Uint((*timestamp).into())],
+            Query::EvmCall {
+                chain_id,
+                contract,
+                calldata,
+            } => vec![
+                Token::Uint((*chain_id).into()),
+                Token::Address(*contract),
+                Token::Bytes(calldata.clone()),
+            ],
+        };
+        encode(&[
+            Token::String(self.query_type().to_string()),
+            Token::Bytes(encode(&parameters)),
+        ])
+    }
+
+    pub(crate) fn query_id(&self) -> H256 {
+        Keccak256::hash(&self.query_data())
+    }
+}
+
+/// Encodes a numeric value, as reported for `SpotPrice` and `NumericApiResponse` queries.
+pub(crate) fn encode_uint(value: U256) -> Vec<u8> {
+    encode(&[Token::Uint(value)])
+}
+
+pub(crate) fn decode_uint(value: &[u8]) -> U256 {
+    decode(&[ParamType::Uint(256)], value)
+        .expect("value is uint256")
+        .remove(0)
+        .into_uint()
+        .unwrap()
+}
+
+/// Encodes a decimal price (e.g. `"4.39"`) with 18 decimals, as reported for `SpotPrice` queries.
+pub(crate) fn encode_price(price: &str) -> Vec<u8> {
+    encode_uint(parse_price(price).expect("price is valid"))
+}
+
+/// Parses a decimal price (e.g. `"4.39"`) exactly, scaled to 18 decimals.
+pub(crate) fn parse_price(price: &str) -> Result<U256, String> {
+    const DECIMALS: usize = 18;
+    let (whole, fraction) = price.split_once('.').unwrap_or((price, ""));
+    if whole.is_empty()
+        || fraction.len() > DECIMALS
+        || !whole
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return Err(format!("invalid price {price}"));
+    }
+    U256::from_dec_str(&format!("{whole}{fraction:0<DECIMALS$}"))
+        .map_err(|e| format!("invalid price {price}: {e:?}"))
+}
+
+/// Encodes a random number, as reported for `TellorRNG` queries.
+pub(crate) fn encode_random(value: H256) -> Vec<u8> {
+    encode(&[Token::FixedBytes(value.0.to_vec())])
+}
+
+pub(crate) fn decode_random(value: &[u8]) -> H256 {
+    let bytes = decode(&[ParamType::FixedBytes(32)], value)
+        .expect("value is bytes32")
+        .remove(0)
+        .into_fixed_bytes()
+        .unwrap();
+    H256::from_slice(&bytes)
+}
+
+/// Encodes the result of a call and the timestamp of the block used, as reported for `EVMCall`
+/// queries.
+pub(crate) fn encode_evm_call_response(result: Vec<u8>, timestamp: u64) -> Vec<u8> {
+    encode(&[Token::Bytes(result), Token::Uint(timestamp.into())])
+}
+
+pub(crate) fn decode_evm_call_response(value: &[u8]) -> (Vec<u8>, u64) {
+    let mut tokens = decode(&[ParamType::Bytes, ParamType::Uint(256)], value)
+        .expect("value is (bytes, uint256)");
+    let timestamp = tokens.remove(1).into_uint().unwrap().as_u64();
+    (tokens.remove(0).into_bytes().unwrap(), timestamp)
+}
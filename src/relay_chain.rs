@@ -69,6 +69,14 @@ fn get_host_config() -> HostConfiguration<BlockNumber> {
         max_upward_message_size: 51200,
         max_upward_message_num_per_candidate: 10,
         max_downward_message_size: 51200,
+        // allows the hrmp channels opened between parachains in exported chain specs
+        hrmp_max_parachain_outbound_channels: 4,
+        hrmp_max_parachain_inbound_channels: 4,
+        hrmp_channel_max_capacity: crate::chain_spec::HRMP_CHANNEL_MAX_CAPACITY,
+        hrmp_channel_max_total_size: crate::chain_spec::HRMP_CHANNEL_MAX_CAPACITY
+            * crate::parachains::MAX_HRMP_MESSAGE_SIZE as u32,
+        hrmp_channel_max_message_size: crate::parachains::MAX_HRMP_MESSAGE_SIZE as u32,
+        hrmp_max_message_num_per_candidate: 10,
         ..Default::default()
     }
 }
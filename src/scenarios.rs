@@ -0,0 +1,410 @@
+use crate::{
+    parachains::{
+        evm::{self, contracts::logs},
+        keyring::Keyring,
+        scenario::Scenario,
+    },
+    query::{self, Query},
+    EvmParachain, OracleConsumerParachain,
+};
+use frame_support::traits::Get;
+use oracle_consumer_runtime::{AccountId, Balance, Balances, RuntimeEvent, System};
+use serde::Deserialize;
+use sp_core::{bytes::from_hex, U256};
+use std::{
+    any::Any,
+    collections::BTreeMap,
+    fs,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    thread,
+};
+use tellor::{Address, VoteResult, DAYS, HOURS, MINUTES};
+use xcm_emulator::TestExt;
+
+/// A scenario file, describing the steps of a cross-chain flow and the events and balances
+/// expected along the way. Actors are referred to by their well-known keyring names (e.g. `BOB` on
+/// the oracle consumer parachain or `BALTHAZAR` on the evm parachain), or by an alias declared
+/// under `actors`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct ScenarioFile {
+    pub(crate) name: Option<String>,
+    #[serde(default)]
+    pub(crate) actors: BTreeMap<String, String>,
+    pub(crate) steps: Vec<Step>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub(crate) enum Step {
+    Deploy,
+    Register,
+    Stake {
+        staker: String,
+        account: String,
+        /// Defaults to the minimum stake amount.
+        amount: Option<Amount>,
+    },
+    Report {
+        query: QuerySpec,
+        value: ValueSpec,
+    },
+    Tip {
+        tipper: String,
+        query: QuerySpec,
+        amount: Amount,
+    },
+    Dispute {
+        initiator: String,
+        beneficiary: String,
+    },
+    Vote(Vec<VoteSpec>),
+    Advance(Time),
+    Tally(Outcome),
+    Execute,
+    Expect(Expectation),
+}
+
+/// An amount, either as a number or as a string for amounts exceeding the range of a `u64`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Amount {
+    Number(u64),
+    Text(String),
+}
+
+impl Amount {
+    fn value(&self) -> Result<u128, String> {
+        match self {
+            Amount::Number(amount) => Ok((*amount).into()),
+            Amount::Text(amount) => amount
+                .parse()
+                .map_err(|e| format!("invalid amount {amount}: {e}")),
+        }
+    }
+}
+
+/// A duration, either in seconds or as a string with a `s`, `m`, `h` or `d` suffix (e.g. `11h`).
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum Time {
+    Seconds(u64),
+    Text(String),
+}
+
+impl Time {
+    fn seconds(&self) -> Result<u64, String> {
+        let text = match self {
+            Time::Seconds(seconds) => return Ok(*seconds),
+            Time::Text(text) => text.trim(),
+        };
+        let (value, unit) = text.split_at(text.len().saturating_sub(1));
+        let unit = match unit {
+            "s" => 1,
+            "m" => MINUTES,
+            "h" => HOURS,
+            "d" => DAYS,
+            _ => {
+                return text
+                    .parse()
+                    .map_err(|e| format!("invalid time {text}: {e}"))
+            }
+        };
+        value
+            .parse::<u64>()
+            .map(|value| value * unit)
+            .map_err(|e| format!("invalid time {text}: {e}"))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub(crate) enum QuerySpec {
+    SpotPrice {
+        asset: String,
+        currency: String,
+    },
+    NumericApiResponse {
+        url: String,
+        parse_str: String,
+    },
+    TellorRng {
+        timestamp: u64,
+    },
+    EvmCall {
+        chain_id: u64,
+        contract: String,
+        calldata: String,
+    },
+}
+
+impl QuerySpec {
+    fn query(&self) -> Result<Query, String> {
+        Ok(match self {
+            QuerySpec::SpotPrice { asset, currency } => Query::spot_price(asset, currency),
+            QuerySpec::NumericApiResponse { url, parse_str } => {
+                Query::numeric_api_response(url, parse_str)
+            }
+            QuerySpec::TellorRng { timestamp } => Query::tellor_rng(*timestamp),
+            QuerySpec::EvmCall {
+                chain_id,
+                contract,
+                calldata,
+            } => Query::evm_call(*chain_id, address(contract)?, bytes(calldata)?),
+        })
+    }
+}
+
+/// A reported value, encoded as per the data spec of its query type.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub(crate) enum ValueSpec {
+    /// A decimal price as a string (e.g. `"4.39"`), so that it is encoded exactly.
+    Price(String),
+    Uint(Amount),
+    /// Hex-encoded bytes, reported as is.
+    Bytes(String),
+}
+
+impl ValueSpec {
+    fn encode(&self) -> Result<Vec<u8>, String> {
+        match self {
+            ValueSpec::Price(price) => query::parse_price(price).map(query::encode_uint),
+            ValueSpec::Uint(value) => Ok(query::encode_uint(U256::from(value.value()?))),
+            ValueSpec::Bytes(value) => bytes(value),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct VoteSpec {
+    pub(crate) voter: String,
+    /// Whether the voter supports the dispute, with `null` voting the dispute invalid.
+    pub(crate) supports: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Outcome {
+    Passed,
+    Failed,
+    Invalid,
+}
+
+impl From<&Outcome> for VoteResult {
+    fn from(outcome: &Outcome) -> Self {
+        match outcome {
+            Outcome::Passed => VoteResult::Passed,
+            Outcome::Failed => VoteResult::Failed,
+            Outcome::Invalid => VoteResult::Invalid,
+        }
+    }
+}
+
+/// Events expected to have been emitted within the current block of each parachain, along with
+/// expected balances.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct Expectation {
+    /// Names of tellor pallet events expected on the oracle consumer parachain.
+    #[serde(default)]
+    pub(crate) oracle_consumer_events: Vec<String>,
+    /// Names of contract events expected on the evm parachain.
+    #[serde(default)]
+    pub(crate) evm_events: Vec<String>,
+    /// Native balances expected on the oracle consumer parachain.
+    #[serde(default)]
+    pub(crate) balances: BTreeMap<String, Amount>,
+    /// xcTRB balances expected on the evm parachain.
+    #[serde(default)]
+    pub(crate) xctrb_balances: BTreeMap<String, Amount>,
+}
+
+/// Loads a YAML or JSON scenario file, depending on its extension.
+pub(crate) fn load(path: &Path) -> Result<ScenarioFile, String> {
+    let contents =
+        fs::read_to_string(path).map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    let parsed = match path.extension().and_then(|e| e.to_str()) {
+        Some("json") => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+        Some("yaml" | "yml") => serde_yaml::from_str(&contents).map_err(|e| e.to_string()),
+        _ => return Err(format!("unsupported scenario file {}", path.display())),
+    };
+    parsed.map_err(|e| format!("could not parse {}: {e}", path.display()))
+}
+
+/// Runs the scenario file against a fresh network, returning a description of the first failing
+/// step or assertion.
+pub fn run_file(path: &Path) -> Result<(), String> {
+    let file = load(path)?;
+    // the externalities of each parachain are thread-local, so each scenario runs on its own
+    // thread against a freshly built network
+    thread::spawn(move || file.run())
+        .join()
+        .map_err(panic_message)?
+}
+
+impl ScenarioFile {
+    pub(crate) fn run(&self) -> Result<(), String> {
+        if let Some(name) = &self.name {
+            tracing::info!(target: "scenarios", "running scenario: {name}");
+        }
+        let mut scenario = Scenario::new();
+        for (index, step) in self.steps.iter().enumerate() {
+            // steps assert the expected events by panicking, so panics are caught to report the
+            // failing step
+            scenario = panic::catch_unwind(AssertUnwindSafe(|| self.step(scenario, step)))
+                .unwrap_or_else(|payload| Err(panic_message(payload)))
+                .map_err(|e| format!("step {} ({step:?}): {e}", index + 1))?;
+        }
+        Ok(())
+    }
+
+    fn step(&self, scenario: Scenario, step: &Step) -> Result<Scenario, String> {
+        Ok(match step {
+            Step::Deploy => scenario.deploy(),
+            Step::Register => scenario.register(),
+            Step::Stake {
+                staker,
+                account,
+                amount,
+            } => {
+                let amount = match amount {
+                    Some(amount) => amount.value()?,
+                    None => <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get(),
+                };
+                scenario.stake(self.address(staker)?, self.account(account)?, amount)
+            }
+            Step::Report { query, value } => scenario.report(&query.query()?, &value.encode()?),
+            Step::Tip {
+                tipper,
+                query,
+                amount,
+            } => scenario.tip(self.account(tipper)?, &query.query()?, amount.value()?),
+            Step::Dispute {
+                initiator,
+                beneficiary,
+            } => scenario.dispute(self.account(initiator)?, self.address(beneficiary)?),
+            Step::Vote(votes) => {
+                let votes = votes
+                    .iter()
+                    .map(|v| Ok((self.account(&v.voter)?, v.supports)))
+                    .collect::<Result<Vec<_>, String>>()?;
+                scenario.vote(&votes)
+            }
+            Step::Advance(time) => scenario.advance(time.seconds()?),
+            Step::Tally(outcome) => scenario.tally(outcome.into()),
+            Step::Execute => scenario.execute(),
+            Step::Expect(expectation) => {
+                self.check(expectation)?;
+                scenario
+            }
+        })
+    }
+
+    fn check(&self, expectation: &Expectation) -> Result<(), String> {
+        let balances = expectation
+            .balances
+            .iter()
+            .map(|(actor, amount)| Ok((actor, self.account(actor)?, amount.value()?)))
+            .collect::<Result<Vec<_>, String>>()?;
+        OracleConsumerParachain::execute_with(|| {
+            let emitted: Vec<String> = System::events()
+                .into_iter()
+                .filter_map(|r| match r.event {
+                    RuntimeEvent::Tellor(event) => Some(Keyring::annotate(&format!("{event:?}"))),
+                    _ => None,
+                })
+                .collect();
+            for name in &expectation.oracle_consumer_events {
+                if !emitted.iter().any(|e| is_event(e, name)) {
+                    return Err(format!(
+                        "expected event {name} not emitted on oracle consumer parachain, emitted: {emitted:?}"
+                    ));
+                }
+            }
+            for (actor, account, expected) in &balances {
+                let balance: Balance = Balances::free_balance(account);
+                if balance != *expected {
+                    return Err(format!(
+                        "expected balance of {actor} to be {expected}, was {balance}"
+                    ));
+                }
+            }
+            Ok(())
+        })?;
+
+        let balances = expectation
+            .xctrb_balances
+            .iter()
+            .map(|(actor, amount)| Ok((actor, self.address(actor)?, amount.value()?)))
+            .collect::<Result<Vec<_>, String>>()?;
+        EvmParachain::execute_with(|| {
+            let emitted = logs::formatted();
+            for name in &expectation.evm_events {
+                if !emitted.iter().any(|l| l.contains(&format!(".{name}("))) {
+                    return Err(format!(
+                        "expected event {name} not emitted on evm parachain, emitted: {emitted:?}"
+                    ));
+                }
+            }
+            for (actor, address, expected) in &balances {
+                let balance = evm::xctrb_balance(*address);
+                if balance != *expected {
+                    return Err(format!(
+                        "expected xcTRB balance of {actor} to be {expected}, was {balance}"
+                    ));
+                }
+            }
+            Ok(())
+        })
+    }
+
+    // resolves an actor, by either of its names, to an account on the oracle consumer parachain
+    fn account(&self, actor: &str) -> Result<AccountId, String> {
+        let name = self.resolve(actor);
+        Keyring::from_name(name)
+            .map(Keyring::account)
+            .ok_or_else(|| format!("unknown oracle consumer parachain account {name}"))
+    }
+
+    // resolves an actor, by either of its names, to an address on the evm parachain
+    fn address(&self, actor: &str) -> Result<Address, String> {
+        let name = self.resolve(actor);
+        Keyring::from_name(name)
+            .map(Keyring::address)
+            .map_or_else(|| address(name), Ok)
+            .map_err(|_| format!("unknown evm parachain address {name}"))
+    }
+
+    fn resolve<'a>(&'a self, actor: &'a str) -> &'a str {
+        self.actors.get(actor).map(String::as_str).unwrap_or(actor)
+    }
+}
+
+// whether the debug formatted event has the name
+fn is_event(event: &str, name: &str) -> bool {
+    event
+        .strip_prefix(name)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '(', '{']))
+}
+
+fn address(value: &str) -> Result<Address, String> {
+    let bytes = bytes(value)?;
+    (bytes.len() == 20)
+        .then(|| Address::from_slice(&bytes))
+        .ok_or_else(|| format!("invalid address {value}"))
+}
+
+fn bytes(value: &str) -> Result<Vec<u8>, String> {
+    from_hex(value).map_err(|e| format!("invalid hex {value}: {e:?}"))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<String>()
+        .cloned()
+        .or_else(|| payload.downcast_ref::<&str>().map(|s| s.to_string()))
+        .unwrap_or_else(|| "scenario panicked".to_string())
+}
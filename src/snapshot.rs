@@ -0,0 +1,211 @@
+use crate::{parachains::evm::contracts::logs, EvmParachain, OracleConsumerParachain, Rococo};
+use std::{env, fmt::Debug, fs, path::PathBuf};
+use xcm_emulator::TestExt;
+
+/// Environment variable selecting the event snapshot mode: `write` to (re)generate the golden
+/// files or `check` to diff the event streams of each chain against them.
+pub(crate) const EVENT_SNAPSHOT: &str = "EVENT_SNAPSHOT";
+
+// the names of event fields and contract event parameters holding timestamps, which are set from
+// the current time when each chain is initialised
+const TIMESTAMP_FIELDS: &[&str] = &[
+    "time",
+    "timestamp",
+    "start_date",
+    "_timestamp",
+    "_startDate",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Mode {
+    Write,
+    Check,
+}
+
+impl Mode {
+    fn from_env() -> Option<Self> {
+        match env::var(EVENT_SNAPSHOT).ok()?.as_str() {
+            "write" => Some(Mode::Write),
+            "check" => Some(Mode::Check),
+            mode => panic!("invalid {EVENT_SNAPSHOT} value {mode}, expected 'write' or 'check'"),
+        }
+    }
+}
+
+/// Writes the normalised event streams of each chain to the golden file with the name, or diffs
+/// them against it, depending on the `EVENT_SNAPSHOT` environment variable. Does nothing when
+/// unset. Must be called at the end of a test, outside of `execute_with`.
+pub(crate) fn assert_events(name: &str) {
+    let Some(mode) = Mode::from_env() else {
+        return;
+    };
+    let events = format(&[
+        ("Rococo", Rococo::execute_with(|| stream(Rococo::events()))),
+        (
+            "EvmParachain",
+            EvmParachain::execute_with(|| evm_stream(EvmParachain::events())),
+        ),
+        (
+            "OracleConsumerParachain",
+            OracleConsumerParachain::execute_with(|| stream(OracleConsumerParachain::events())),
+        ),
+    ]);
+    let path = path(name);
+    match mode {
+        Mode::Write => {
+            fs::create_dir_all(path.parent().unwrap()).expect("could not create snapshots dir");
+            fs::write(&path, events).expect("could not write event snapshot");
+        }
+        Mode::Check => {
+            let expected = fs::read_to_string(&path).unwrap_or_else(|e| {
+                panic!(
+                    "could not read {}: {e}, generate using {EVENT_SNAPSHOT}=write cargo test",
+                    path.display()
+                )
+            });
+            if expected != events {
+                panic!(
+                    "events differ from snapshot {}, regenerate using {EVENT_SNAPSHOT}=write cargo \
+                     test if intended:\n{}",
+                    path.display(),
+                    diff(&expected, &events)
+                );
+            }
+        }
+    }
+}
+
+fn stream(events: Vec<impl Debug>) -> Vec<String> {
+    events
+        .iter()
+        .map(|e| normalise(&format!("{e:?}")))
+        .collect()
+}
+
+// contract logs are decoded, so that their parameters are named and can be normalised
+fn evm_stream(events: Vec<moonbeam_runtime::RuntimeEvent>) -> Vec<String> {
+    events
+        .iter()
+        .map(|e| match e {
+            moonbeam_runtime::RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => {
+                logs::decode(log)
+                    .map(|l| l.to_string())
+                    .unwrap_or_else(|| format!("{e:?}"))
+            }
+            _ => format!("{e:?}"),
+        })
+        .map(|e| normalise(&e))
+        .collect()
+}
+
+/// Formats the event streams of each chain, one event per line under a header for each chain.
+pub(crate) fn format(chains: &[(&str, Vec<String>)]) -> String {
+    chains
+        .iter()
+        .map(|(chain, events)| {
+            let events: String = events.iter().map(|e| format!("{e}\n")).collect();
+            format!("# {chain}\n{events}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Normalises a debug formatted event, replacing 32 byte hashes (as hex or byte arrays) with
+/// `<hash>` and the values of timestamp fields (e.g. `time: 1697712345`) with `<timestamp>`, as
+/// these vary between runs.
+pub(crate) fn normalise(event: &str) -> String {
+    let chars: Vec<char> = event.chars().collect();
+    let mut normalised = String::with_capacity(event.len());
+    let mut i = 0;
+    while i < chars.len() {
+        let boundary = i == 0 || !(chars[i - 1].is_alphanumeric() || chars[i - 1] == '_');
+        if boundary && chars[i..].starts_with(&['0', 'x']) {
+            let end = scan(&chars, i + 2, |c| c.is_ascii_hexdigit());
+            if end - i - 2 == 64 {
+                normalised.push_str("<hash>");
+                i = end;
+                continue;
+            }
+        }
+        if chars[i] == '[' {
+            if let Some(end) = byte_array_end(&chars, i) {
+                normalised.push_str("<hash>");
+                i = end;
+                continue;
+            }
+        }
+        if boundary && (chars[i].is_alphabetic() || chars[i] == '_') {
+            let end = scan(&chars, i, |c| c.is_alphanumeric() || c == '_');
+            let field: String = chars[i..end].iter().collect();
+            normalised.push_str(&field);
+            i = end;
+            if TIMESTAMP_FIELDS.contains(&field.as_str()) && chars[i..].starts_with(&[':', ' ']) {
+                let value = scan(&chars, i + 2, |c| c.is_ascii_digit());
+                if value > i + 2 {
+                    normalised.push_str(": <timestamp>");
+                    i = value;
+                }
+            }
+            continue;
+        }
+        normalised.push(chars[i]);
+        i += 1;
+    }
+    normalised
+}
+
+// the index after the run of characters matching the predicate
+fn scan(chars: &[char], start: usize, predicate: impl Fn(char) -> bool) -> usize {
+    chars[start..]
+        .iter()
+        .position(|c| !predicate(*c))
+        .map_or(chars.len(), |p| start + p)
+}
+
+// the index after a debug formatted array of 32 bytes starting at the index, if any
+fn byte_array_end(chars: &[char], start: usize) -> Option<usize> {
+    let end = start + chars[start..].iter().position(|c| *c == ']')?;
+    let contents: String = chars[start + 1..end].iter().collect();
+    let bytes: Vec<_> = contents.split(", ").map(|b| b.parse::<u8>()).collect();
+    (bytes.len() == 32 && bytes.iter().all(Result::is_ok)).then_some(end + 1)
+}
+
+/// A line diff of the expected and actual snapshots, with removed lines prefixed by `-` and added
+/// lines by `+`.
+pub(crate) fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+    // longest common subsequence lengths of the suffixes of each
+    let mut lcs = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            diff.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if i < expected.len() && (j == actual.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    diff.join("\n")
+}
+
+fn path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(format!("{name}.events"))
+}
@@ -4,9 +4,9 @@ use oracle_consumer_runtime::Tellor;
 use parachains::evm::{
     contracts::governance::GOVERNANCE_CONTRACT_ADDRESS,
     contracts::registry::REGISTRY_CONTRACT_ADDRESS, contracts::staking::STAKING_CONTRACT_ADDRESS,
-    BALTHAZAR, DOROTHY, PALLET_DERIVATIVE_ACCOUNT, XCTRB_ADDRESS,
+    PALLET_DERIVATIVE_ACCOUNT, XCTRB_ADDRESS,
 };
-use parachains::oracle_consumer::{BOB, CHARLIE, DAVE};
+use parachains::keyring::Keyring;
 use sp_runtime::{
     app_crypto::ByteArray,
     traits::{Hash, Keccak256},
@@ -52,6 +52,489 @@ fn deploying_contracts_to_evm_parachain_works() {
     });
 }
 
+#[test]
+fn signed_transaction_on_evm_parachain_charges_sender_for_gas() {
+    init_tracing();
+
+    // transfer value via signed transaction, which also ensures nonce incremented and gas charged
+    EvmParachain::execute_with(|| {
+        use parachains::evm::transaction::*;
+        let amount = 10u128.pow(18);
+        let balance = balance_of(Keyring::Charlie.address());
+        let receipt = Transaction::call(
+            Keyring::Bob.address(),
+            Keyring::Charlie.address(),
+            Vec::new(),
+        )
+        .value(amount)
+        .submit();
+        assert_eq!(receipt.to, Keyring::Charlie.address());
+        assert_eq!(receipt.used_gas, 21_000.into());
+        assert_eq!(balance_of(Keyring::Charlie.address()), balance + amount);
+    });
+}
+
+#[test]
+fn loading_contract_bytecode_from_foundry_artifact_works() {
+    use parachains::evm::contracts::bytecode;
+    use std::fs;
+
+    // write foundry artifact containing existing bytecode
+    let contracts = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("contracts");
+    let bytecode = fs::read_to_string(contracts.join("ParachainRegistry.hex")).unwrap();
+    let out = std::env::temp_dir().join(format!("tellor-contracts-{}", std::process::id()));
+    let artifact = out
+        .join("ParachainRegistry.sol")
+        .join("ParachainRegistry.json");
+    fs::create_dir_all(artifact.parent().unwrap()).unwrap();
+    fs::write(
+        &artifact,
+        serde_json::json!({ "bytecode": { "object": format!("0x{bytecode}") } }).to_string(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        bytecode::from_artifact(&artifact),
+        bytecode::from_hex_file(&contracts.join("ParachainRegistry.hex"))
+    );
+    fs::remove_dir_all(out).unwrap();
+}
+
+#[test]
+fn gas_snapshot_check_fails_when_gas_exceeds_tolerance() {
+    use parachains::evm::contracts::gas;
+
+    let snapshot = gas::parse(
+        "ParachainGovernance:executeVote (gas: 200000)\n\
+         ParachainStaking:depositParachainStake (gas: 100000)\n",
+    );
+    assert_eq!(gas::parse(&gas::format(&snapshot)), snapshot);
+    assert!(gas::check(
+        &snapshot,
+        "ParachainStaking:depositParachainStake",
+        90_000,
+        1
+    )
+    .is_ok());
+    assert!(gas::check(
+        &snapshot,
+        "ParachainStaking:depositParachainStake",
+        101_000,
+        1
+    )
+    .is_ok());
+    assert!(gas::check(
+        &snapshot,
+        "ParachainStaking:depositParachainStake",
+        101_001,
+        1
+    )
+    .is_err());
+    assert!(gas::check(&snapshot, "ParachainStaking:withdrawParachainStake", 1, 1).is_err());
+}
+
+#[test]
+fn gas_snapshot_merge_keeps_maximum_gas_used() {
+    use parachains::evm::contracts::gas;
+
+    let mut snapshot = gas::parse("ParachainGovernance:executeVote (gas: 200000)\n");
+    assert!(!gas::merge(
+        &mut snapshot,
+        "ParachainGovernance:executeVote".to_string(),
+        150_000
+    ));
+    assert!(gas::merge(
+        &mut snapshot,
+        "ParachainGovernance:executeVote".to_string(),
+        250_000
+    ));
+    assert!(gas::merge(
+        &mut snapshot,
+        "ParachainRegistry:register".to_string(),
+        100_000
+    ));
+    assert_eq!(
+        gas::format(&snapshot),
+        "ParachainGovernance:executeVote (gas: 250000)\n\
+         ParachainRegistry:register (gas: 100000)\n"
+    );
+}
+
+#[test]
+fn event_snapshot_normalises_timestamps_and_hashes() {
+    let hash = format!("0x{}", "ab".repeat(32));
+    let bytes = format!("{:?}", [7u8; 32]);
+    assert_eq!(
+        snapshot::normalise(&format!(
+            "Tellor(NewReport {{ query_id: {hash}, time: 1697712345, nonce: 0 }}) \
+             XcmpQueue(Success {{ message_hash: Some({bytes}), weight: 1697712345 }}) \
+             ParachainGovernance.NewParachainDispute(_paraId: 3000, _timestamp: 1697712345) \
+             Address(0x773539d4ac0e786233d90a233654ccee26a613d9) runtime_time: 1697712345"
+        )),
+        "Tellor(NewReport { query_id: <hash>, time: <timestamp>, nonce: 0 }) \
+         XcmpQueue(Success { message_hash: Some(<hash>), weight: 1697712345 }) \
+         ParachainGovernance.NewParachainDispute(_paraId: 3000, _timestamp: <timestamp>) \
+         Address(0x773539d4ac0e786233d90a233654ccee26a613d9) runtime_time: 1697712345"
+    );
+}
+
+#[test]
+fn event_snapshot_diff_shows_changed_lines() {
+    let expected = snapshot::format(&[
+        ("Rococo", vec![]),
+        ("EvmParachain", vec!["A".to_string(), "B".to_string()]),
+    ]);
+    let actual = snapshot::format(&[
+        ("Rococo", vec![]),
+        ("EvmParachain", vec!["A".to_string(), "C".to_string()]),
+    ]);
+    assert_eq!(expected, "# Rococo\n\n# EvmParachain\nA\nB\n");
+    assert_eq!(
+        snapshot::diff(&expected, &actual),
+        "  # Rococo\n  \n  # EvmParachain\n  A\n- B\n+ C"
+    );
+}
+
+#[test]
+fn decoding_contract_logs_works() {
+    use ethabi::Token;
+    use parachains::evm::contracts::{logs, staking};
+
+    let event = staking::EVENTS
+        .iter()
+        .find(|e| e.name == "ParachainStakeWithdrawn")
+        .unwrap();
+    let log = ethereum::Log {
+        address: *STAKING_CONTRACT_ADDRESS,
+        topics: vec![event.signature()],
+        data: ethabi::encode(&[
+            Token::Uint(3_000.into()),
+            Token::Address(Keyring::Bob.address()),
+        ]),
+    };
+    let decoded = logs::decode(&log).unwrap();
+    assert_eq!(decoded.contract, "ParachainStaking");
+    assert_eq!(
+        decoded.params,
+        vec![
+            ("_paraId".to_string(), Token::Uint(3_000.into())),
+            (
+                "_staker".to_string(),
+                Token::Address(Keyring::Bob.address())
+            ),
+        ]
+    );
+    assert_eq!(
+        decoded.to_string(),
+        format!(
+            "ParachainStaking({:?}).ParachainStakeWithdrawn(_paraId: 3000, _staker: {:?})",
+            *STAKING_CONTRACT_ADDRESS,
+            Keyring::Bob.address()
+        )
+    );
+
+    // logs from unknown contracts are not decoded
+    assert!(logs::decode(&ethereum::Log {
+        address: Keyring::Bob.address(),
+        ..log
+    })
+    .is_none());
+}
+
+#[test]
+fn exported_chain_specs_register_parachains_with_hrmp_channels() {
+    use polkadot_parachain::primitives::HrmpChannelId;
+    use polkadot_runtime_parachains::{hrmp, paras};
+
+    init_tracing();
+    let specs = chain_spec::chain_specs();
+    let relay = &specs[0];
+    assert_eq!(relay.id, chain_spec::RELAY_CHAIN_ID);
+    assert_eq!(relay.para_id, None);
+
+    // each parachain spec references the relay chain, with the genesis used by the emulator
+    let para_ids: Vec<ParaId> = specs[1..]
+        .iter()
+        .map(|spec| {
+            assert_eq!(
+                spec.relay_chain.as_deref(),
+                Some(chain_spec::RELAY_CHAIN_ID)
+            );
+            spec.para_id.unwrap().into()
+        })
+        .collect();
+    assert_eq!(
+        para_ids,
+        vec![
+            AssetReserveParachain::para_id(),
+            EvmParachain::para_id(),
+            OracleConsumerParachain::para_id(),
+            SecondOracleConsumerParachain::para_id(),
+        ]
+    );
+    assert_eq!(
+        specs[2].genesis.raw,
+        chain_spec::RawGenesis::from(&parachains::evm::genesis())
+    );
+
+    // chain specs round trip through json
+    let json = serde_json::to_string(relay).unwrap();
+    assert!(json.contains("\"chainType\":\"Local\""));
+    assert_eq!(
+        &serde_json::from_str::<chain_spec::ChainSpec>(&json).unwrap(),
+        relay
+    );
+
+    // the relay chain genesis registers each parachain and opens channels between all of them
+    let storage = Storage {
+        top: relay
+            .genesis
+            .raw
+            .top
+            .iter()
+            .map(|(k, v)| {
+                (
+                    sp_core::bytes::from_hex(k).unwrap(),
+                    sp_core::bytes::from_hex(v).unwrap(),
+                )
+            })
+            .collect(),
+        children_default: Default::default(),
+    };
+    sp_io::TestExternalities::new(storage).execute_with(|| {
+        let mut expected = para_ids.clone();
+        expected.sort();
+        assert_eq!(
+            paras::Pallet::<rococo_runtime::Runtime>::parachains(),
+            expected
+        );
+        for sender in &para_ids {
+            for recipient in para_ids.iter().filter(|r| *r != sender) {
+                assert!(hrmp::HrmpChannels::<rococo_runtime::Runtime>::contains_key(
+                    HrmpChannelId {
+                        sender: *sender,
+                        recipient: *recipient,
+                    }
+                ));
+            }
+        }
+    });
+}
+
+#[test]
+fn genesis_loads_from_raw_chain_spec_and_state_pairs_fixtures() {
+    use frame_support::traits::fungible::Inspect;
+    use std::path::PathBuf;
+
+    init_tracing();
+    let fixtures = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures/genesis");
+    let raw = chain_spec::load_genesis(&fixtures.join("evm_parachain.raw.json")).unwrap();
+    let pairs = chain_spec::load_genesis(&fixtures.join("evm_parachain.pairs.json")).unwrap();
+    assert_eq!(raw.top, pairs.top);
+    // key/value dumps only include the top trie
+    assert_eq!(raw.children_default.len(), 1);
+    assert!(pairs.children_default.is_empty());
+
+    // storage is read by the runtime as from its own genesis
+    sp_io::TestExternalities::new(pairs.clone()).execute_with(|| {
+        assert_eq!(
+            parachain_info::Pallet::<moonbeam_runtime::Runtime>::parachain_id(),
+            ParaId::new(2_000)
+        );
+        assert_eq!(
+            moonbeam_runtime::Balances::total_issuance(),
+            1_000 * 10u128.pow(18)
+        );
+    });
+
+    // genesis must be for the parachain it is loaded for
+    assert!(chain_spec::check_parachain_genesis(pairs.clone(), ParaId::new(2_000)).is_ok());
+    assert_eq!(
+        chain_spec::check_parachain_genesis(pairs, ParaId::new(3_000)).unwrap_err(),
+        "genesis is for para id 2000, expected 3000"
+    );
+
+    // exported genesis loads back unchanged
+    let genesis = parachains::evm::genesis();
+    assert_eq!(
+        Storage::try_from(&chain_spec::RawGenesis::from(&genesis))
+            .unwrap()
+            .top,
+        genesis.top
+    );
+}
+
+#[test]
+fn parachain_boots_from_genesis_file() {
+    use parachains::oracle_consumer::GENESIS_FILE;
+    use std::{fs, path::PathBuf};
+
+    init_tracing();
+    // export the genesis of the oracle consumer parachain as state pairs, marked so that booting
+    // from the file can be distinguished from the built genesis
+    let marker = (b"genesis-file".to_vec(), b"oracle-consumer".to_vec());
+    let mut genesis = parachains::oracle_consumer::genesis();
+    genesis.top.insert(marker.0.clone(), marker.1.clone());
+    let path: PathBuf = std::env::temp_dir().join(format!(
+        "tellor-genesis-{}-oracle-consumer.json",
+        std::process::id()
+    ));
+    fs::write(
+        &path,
+        serde_json::to_string(&chain_spec::RawGenesis::from(&genesis).top).unwrap(),
+    )
+    .unwrap();
+
+    // the network is rebuilt with the oracle consumer parachain booted from the file
+    chain_spec::set_genesis_file(GENESIS_FILE, Some(path.clone()));
+    RococoMockNet::reset();
+    chain_spec::set_genesis_file(GENESIS_FILE, None);
+    fs::remove_file(&path).unwrap();
+    OracleConsumerParachain::execute_with(|| {
+        assert_eq!(
+            sp_io::storage::get(&marker.0).as_deref(),
+            Some(&marker.1[..])
+        );
+        assert_eq!(
+            parachain_info::Pallet::<oracle_consumer_runtime::Runtime>::parachain_id(),
+            OracleConsumerParachain::para_id()
+        );
+    });
+
+    // the parachain registers with the contracts and receives stakes as usual
+    let amount = <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+    parachains::scenario::Scenario::new()
+        .deploy()
+        .register()
+        .stake(Keyring::Bob.address(), Keyring::Bob.account(), amount);
+}
+
+#[test]
+fn evm_parachain_boots_from_exported_state_with_deployed_contracts() {
+    use parachains::evm::{
+        contracts::{erc20, staking},
+        GENESIS_FILE,
+    };
+    use std::fs;
+    use tellor::U256;
+
+    init_tracing();
+    // export the chain specs and the evm parachain state with the contracts deployed, as written by
+    // the tellor-chain-specs binary
+    let dir = std::env::temp_dir().join(format!("tellor-chain-specs-{}", std::process::id()));
+    let paths = chain_spec::export(&dir).unwrap();
+    let path = dir.join(chain_spec::DEPLOYED_EVM_STATE);
+    assert!(paths.contains(&path));
+
+    // the network is rebuilt with the evm parachain booted from the exported state, without
+    // deploying the contracts or creating the xcTRB asset
+    chain_spec::set_genesis_file(GENESIS_FILE, Some(path));
+    RococoMockNet::reset();
+    chain_spec::set_genesis_file(GENESIS_FILE, None);
+    fs::remove_dir_all(&dir).unwrap();
+    let para_id = OracleConsumerParachain::para_id();
+    let staker = Keyring::Bob.address();
+    EvmParachain::execute_with(|| {
+        for contract in [
+            *REGISTRY_CONTRACT_ADDRESS,
+            *STAKING_CONTRACT_ADDRESS,
+            *GOVERNANCE_CONTRACT_ADDRESS,
+        ] {
+            assert!(pallet_evm::AccountCodes::<moonbeam_runtime::Runtime>::contains_key(contract));
+        }
+        assert_eq!(erc20::balance_of(staker), U256::zero());
+        assert_eq!(
+            staking::get_parachain_staker_info(para_id, staker).staked_balance,
+            U256::zero()
+        );
+    });
+
+    // the deployed contracts register the parachain and receive stakes of xcTRB as usual
+    let amount = <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+    parachains::scenario::Scenario::new()
+        .register()
+        .stake(staker, Keyring::Bob.account(), amount);
+    EvmParachain::execute_with(|| {
+        assert_eq!(
+            staking::get_parachain_staker_info(para_id, staker).staked_balance,
+            U256::from(amount)
+        );
+    });
+}
+
+#[test]
+fn keyring_pairs_consumer_accounts_with_evm_stakers() {
+    use parachains::{
+        evm::{BALTHAZAR, DOROTHY},
+        keyring::Funding,
+        oracle_consumer::{BOB, DAVE},
+    };
+    use sp_runtime::traits::IdentifyAccount;
+
+    init_tracing();
+    let bob = Keyring::Bob;
+    assert_eq!(bob.account(), *BOB);
+    assert_eq!(bob.address(), *BALTHAZAR);
+    assert_eq!(sp_runtime::AccountId32::from(bob.pair().public()), *BOB);
+    assert_eq!(
+        account::EthereumSigner::from(bob.evm_pair().public()).into_account(),
+        account::AccountId20::from(*BALTHAZAR)
+    );
+
+    // actors are found from either identity
+    assert_eq!(Keyring::from_name("balthazar"), Some(bob));
+    assert_eq!(Keyring::from_name("CHARLIE"), Some(Keyring::Charlie));
+    assert_eq!(Keyring::from_name("Eve"), None);
+    assert_eq!(Keyring::from_account(&DAVE), Some(Keyring::Dave));
+    assert_eq!(Keyring::from_address(*DOROTHY), Some(Keyring::Dave));
+    assert_eq!(
+        Keyring::annotate(&format!(
+            "Transfer {{ from: {}, to: {:?} }}",
+            *BOB, *DOROTHY
+        )),
+        format!(
+            "Transfer {{ from: Bob({}), to: Dorothy({:?}) }}",
+            *BOB, *DOROTHY
+        )
+    );
+
+    // funding mints to the actor on every chain
+    let funding = Funding::default();
+    let balances = || {
+        (
+            Rococo::execute_with(|| {
+                rococo_runtime::Balances::free_balance(Keyring::Dave.account())
+            }),
+            AssetReserveParachain::execute_with(|| {
+                statemine_runtime::Balances::free_balance(Keyring::Dave.account())
+            }),
+            EvmParachain::execute_with(|| {
+                moonbeam_runtime::Balances::free_balance(account::AccountId20::from(
+                    Keyring::Dave.address(),
+                ))
+            }),
+            OracleConsumerParachain::execute_with(|| {
+                oracle_consumer_runtime::Balances::free_balance(Keyring::Dave.account())
+            }),
+            SecondOracleConsumerParachain::execute_with(|| {
+                oracle_consumer_runtime::Balances::free_balance(Keyring::Dave.account())
+            }),
+        )
+    };
+    let before = balances();
+    Keyring::Dave.fund();
+    assert_eq!(
+        balances(),
+        (
+            before.0 + funding.relay,
+            before.1 + funding.asset_reserve,
+            before.2 + funding.evm,
+            before.3 + funding.oracle_consumer,
+            before.4 + funding.oracle_consumer
+        )
+    );
+}
+
 #[test]
 fn register_on_consumer_parachain_registers_with_contracts_on_evm_parachain() {
     init_tracing();
@@ -72,8 +555,394 @@ fn register_on_consumer_parachain_registers_with_contracts_on_evm_parachain() {
         // ensure registry contract called (via pallet derivative account on evm parachain)
         registry::assert_executed(*PALLET_DERIVATIVE_ACCOUNT);
         // ensure ParachainRegistered event emitted by parachain registry contract
-        registry::assert_parachain_registered_event(OracleConsumerParachain::para_id());
+        registry::assert_parachain_registered_event(
+            *PALLET_DERIVATIVE_ACCOUNT,
+            OracleConsumerParachain::para_id(),
+            *PALLET_DERIVATIVE_ACCOUNT,
+        );
     });
+
+    // compare event streams of each chain against golden file when EVENT_SNAPSHOT set
+    snapshot::assert_events(
+        "register_on_consumer_parachain_registers_with_contracts_on_evm_parachain",
+    );
+}
+
+mod registry {
+    use super::*;
+    use frame_support::traits::PalletInfoAccess;
+
+    #[test]
+    fn register_on_consumer_parachain_stores_registration_on_evm_parachain() {
+        init_tracing();
+
+        // deploy parachain registry contract to evm parachain
+        EvmParachain::execute_with(|| {
+            parachains::evm::contracts::registry::deploy();
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // ensure registration stored by registry contract
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            let parachain = registry::Parachain {
+                id: OracleConsumerParachain::para_id().into(),
+                owner: *PALLET_DERIVATIVE_ACCOUNT,
+                pallet_instance: Tellor::index() as u8,
+                stake_amount:
+                    <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get()
+                        .into(),
+                weights: parachains::oracle_consumer::default_weights(),
+            };
+            assert_eq!(
+                registry::get_by_id(OracleConsumerParachain::para_id()),
+                parachain
+            );
+            assert_eq!(
+                registry::get_by_address(*PALLET_DERIVATIVE_ACCOUNT),
+                parachain
+            );
+        });
+    }
+
+    #[test]
+    fn re_register_on_consumer_parachain_updates_weights_on_evm_parachain() {
+        init_tracing();
+
+        // deploy parachain registry contract to evm parachain
+        EvmParachain::execute_with(|| {
+            parachains::evm::contracts::registry::deploy();
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // re-register with updated weights
+        let weights = {
+            let weights = parachains::oracle_consumer::default_weights();
+            tellor::Weights {
+                report_stake_deposited: weights.report_stake_deposited * 2,
+                report_staking_withdraw_request: weights.report_staking_withdraw_request * 2,
+                report_stake_withdrawn: weights.report_stake_withdrawn * 2,
+                report_vote_tallied: weights.report_vote_tallied * 2,
+                report_vote_executed: weights.report_vote_executed * 2,
+                report_slash: weights.report_slash * 2,
+            }
+        };
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register_with_weights(
+                EvmParachain::para_id(),
+                weights.clone(),
+            )
+        });
+
+        // ensure registration updated by registry contract
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            registry::assert_parachain_registered_event(
+                *PALLET_DERIVATIVE_ACCOUNT,
+                OracleConsumerParachain::para_id(),
+                *PALLET_DERIVATIVE_ACCOUNT,
+            );
+            let parachain = registry::get_by_id(OracleConsumerParachain::para_id());
+            assert_eq!(parachain.owner, *PALLET_DERIVATIVE_ACCOUNT);
+            assert_eq!(parachain.weights, weights);
+        });
+    }
+
+    #[test]
+    fn re_register_with_another_owner_changes_owner_on_evm_parachain() {
+        use codec::{Compact, Decode, Encode};
+        use parachains::evm::contracts::registry;
+        use parachains::oracle_consumer::{capture_xcm, send_to_evm_parachain, PARA_ID};
+        init_tracing();
+
+        // deploy parachain registry contract to evm parachain
+        EvmParachain::execute_with(|| {
+            registry::deploy();
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // capture a registration message sent by the tellor pallet, replacing the owner of the
+        // registration within the call to the registry contract, which is prefixed by its length
+        let owner = Keyring::Dave.address();
+        let owner_location = MultiLocation::new(
+            0,
+            X1(AccountKey20 {
+                network: None,
+                key: owner.0,
+            }),
+        );
+        let [mut message]: [Xcm<()>; 1] = OracleConsumerParachain::execute_with(|| {
+            capture_xcm(|| parachains::oracle_consumer::register(EvmParachain::para_id()))
+        })
+        .try_into()
+        .expect("a single message");
+        // the origin is descended into the tellor pallet again when resent
+        assert!(matches!(message.0.remove(0), DescendOrigin(_)));
+        let call = message
+            .0
+            .iter_mut()
+            .find_map(|instruction| match instruction {
+                Transact { call, .. } => Some(call),
+                _ => None,
+            })
+            .expect("registration transacts");
+        let encoded = call.clone().into_encoded();
+        let replaced = (0..encoded.len())
+            .find_map(|start| {
+                let mut data = &encoded[start..];
+                let len = Compact::<u32>::decode(&mut data).ok()?.0 as usize;
+                let input = registry::with_owner(data.get(..len)?, &owner_location)?;
+                let end = encoded.len() - data.len() + len;
+                Some(
+                    [
+                        &encoded[..start],
+                        &Compact(input.len() as u32).encode(),
+                        &input,
+                        &encoded[end..],
+                    ]
+                    .concat(),
+                )
+            })
+            .expect("registration calls register");
+        *call = replaced.into();
+        OracleConsumerParachain::execute_with(|| send_to_evm_parachain(message));
+
+        // ensure owner changed by registry contract, with the registration otherwise unchanged
+        EvmParachain::execute_with(|| {
+            registry::assert_parachain_registered_event(*PALLET_DERIVATIVE_ACCOUNT, PARA_ID, owner);
+            let parachain = registry::get_by_id(PARA_ID);
+            assert_eq!(
+                parachain,
+                registry::Parachain {
+                    id: PARA_ID,
+                    owner,
+                    pallet_instance: Tellor::index() as u8,
+                    stake_amount:
+                        <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get()
+                            .into(),
+                    weights: parachains::oracle_consumer::default_weights(),
+                }
+            );
+            assert_eq!(registry::get_by_address(owner), parachain);
+        });
+    }
+
+    #[test]
+    fn registering_several_parachains_with_registry_works() {
+        use parachains::{
+            evm::pallet_derivative_account,
+            oracle_consumer::{PARA_ID, SECOND_PARA_ID},
+        };
+        init_tracing();
+
+        // deploy parachain registry contract to evm parachain
+        EvmParachain::execute_with(|| {
+            parachains::evm::contracts::registry::deploy();
+        });
+
+        // register both oracle consumer parachains via their tellor pallets, with different weights
+        let weights = parachains::oracle_consumer::default_weights();
+        let second_weights = tellor::Weights {
+            report_stake_deposited: weights.report_stake_deposited * 2,
+            report_slash: weights.report_slash * 2,
+            ..weights.clone()
+        };
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register_with_weights(
+                EvmParachain::para_id(),
+                weights.clone(),
+            )
+        });
+        SecondOracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register_with_weights(
+                EvmParachain::para_id(),
+                second_weights.clone(),
+            )
+        });
+
+        // ensure each registration stored independently, owned by the pallet derivative account of
+        // its parachain
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            let second_owner = pallet_derivative_account(SECOND_PARA_ID);
+            registry::assert_parachain_registered_event(
+                *PALLET_DERIVATIVE_ACCOUNT,
+                PARA_ID,
+                *PALLET_DERIVATIVE_ACCOUNT,
+            );
+            registry::assert_parachain_registered_event(second_owner, SECOND_PARA_ID, second_owner);
+            let stake_amount =
+                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+            let parachain = registry::Parachain {
+                id: PARA_ID,
+                owner: *PALLET_DERIVATIVE_ACCOUNT,
+                pallet_instance: Tellor::index() as u8,
+                stake_amount: stake_amount.into(),
+                weights: weights.clone(),
+            };
+            let second_parachain = registry::Parachain {
+                id: SECOND_PARA_ID,
+                owner: second_owner,
+                pallet_instance: Tellor::index() as u8,
+                stake_amount: stake_amount.into(),
+                weights: second_weights.clone(),
+            };
+            assert_eq!(registry::get_by_id(PARA_ID), parachain);
+            assert_eq!(registry::get_by_id(SECOND_PARA_ID), second_parachain);
+            assert_eq!(
+                registry::get_by_address(*PALLET_DERIVATIVE_ACCOUNT),
+                parachain
+            );
+            assert_eq!(registry::get_by_address(second_owner), second_parachain);
+        });
+    }
+
+    #[test]
+    fn registering_parachain_from_another_account_is_rejected() {
+        use parachains::oracle_consumer::PARA_ID;
+        init_tracing();
+
+        // deploy parachain registry contract to evm parachain
+        EvmParachain::execute_with(|| {
+            parachains::evm::contracts::registry::deploy();
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        let weights = parachains::oracle_consumer::default_weights();
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register_with_weights(
+                EvmParachain::para_id(),
+                weights.clone(),
+            )
+        });
+
+        // attempt to take over registration directly from another account
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            registry::assert_executed(*PALLET_DERIVATIVE_ACCOUNT);
+            let receipt = registry::try_register(
+                Keyring::Dave.address(),
+                PARA_ID,
+                Tellor::index() as u8,
+                1,
+                &MultiLocation::new(
+                    0,
+                    X1(AccountKey20 {
+                        network: None,
+                        key: Keyring::Dave.address().0,
+                    }),
+                ),
+                &weights,
+            );
+            // ensure rejected, as only the pallet derivative account of the parachain may register
+            assert!(matches!(
+                receipt.exit_reason,
+                pallet_evm::ExitReason::Revert(_)
+            ));
+            let parachain = registry::get_by_id(PARA_ID);
+            assert_eq!(parachain.owner, *PALLET_DERIVATIVE_ACCOUNT);
+            assert_eq!(
+                parachain.stake_amount,
+                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get()
+                    .into()
+            );
+            assert_eq!(
+                registry::get_by_address(*PALLET_DERIVATIVE_ACCOUNT),
+                parachain
+            );
+            assert_eq!(registry::get_by_address(Keyring::Dave.address()).id, 0);
+        });
+    }
+}
+
+mod erc20 {
+    use super::*;
+
+    #[test]
+    fn xctrb_transfers_via_erc20_precompile_work() {
+        init_tracing();
+
+        // create trb asset and mint to staker
+        let amount = 100 * 10u128.pow(18);
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::staking;
+            parachains::evm::create_xctrb_asset();
+            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+            staking::mint(asset, Keyring::Bob.address(), amount);
+        });
+
+        // transfer, approve and transfer from via precompile
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::erc20;
+            assert_eq!(erc20::balance_of(Keyring::Bob.address()), amount.into());
+            erc20::transfer(
+                Keyring::Bob.address(),
+                Keyring::Charlie.address(),
+                amount / 4,
+            );
+            erc20::approve(
+                Keyring::Bob.address(),
+                Keyring::Charlie.address(),
+                amount / 2,
+            );
+            erc20::transfer_from(
+                Keyring::Charlie.address(),
+                Keyring::Bob.address(),
+                Keyring::Dave.address(),
+                amount / 4,
+            );
+            // ensure balances and remaining allowance
+            assert_eq!(
+                erc20::balance_of(Keyring::Bob.address()),
+                (amount / 2).into()
+            );
+            assert_eq!(
+                erc20::balance_of(Keyring::Charlie.address()),
+                (amount / 4).into()
+            );
+            assert_eq!(
+                erc20::balance_of(Keyring::Dave.address()),
+                (amount / 4).into()
+            );
+            assert_eq!(
+                erc20::allowance(Keyring::Bob.address(), Keyring::Charlie.address()),
+                (amount / 4).into()
+            );
+
+            // transfers to self leave balances unchanged
+            erc20::transfer(
+                Keyring::Charlie.address(),
+                Keyring::Charlie.address(),
+                amount / 4,
+            );
+            erc20::transfer_from(
+                Keyring::Charlie.address(),
+                Keyring::Bob.address(),
+                Keyring::Bob.address(),
+                amount / 4,
+            );
+            assert_eq!(
+                erc20::balance_of(Keyring::Bob.address()),
+                (amount / 2).into()
+            );
+            assert_eq!(
+                erc20::balance_of(Keyring::Charlie.address()),
+                (amount / 4).into()
+            );
+        });
+    }
 }
 
 mod staking {
@@ -105,24 +974,31 @@ mod staking {
         let amount =
             <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
+            use parachains::evm::contracts::{erc20, staking};
             let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
             // deposit stake
             staking::deposit_parachain_stake(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
-            staking::assert_new_staker_event(*BALTHAZAR, amount);
+            staking::assert_new_staker_event(Keyring::Bob.address(), amount);
             staking::assert_new_parachain_staker_event(
                 OracleConsumerParachain::para_id(),
-                *BALTHAZAR,
-                BOB.to_raw_vec(),
+                Keyring::Bob.address(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
+            // ensure stake transferred to staking contract via precompile
+            erc20::assert_transfer_event(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
+            assert_eq!(erc20::balance_of(*STAKING_CONTRACT_ADDRESS), amount.into());
+            assert_eq!(
+                erc20::allowance(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS),
+                0.into()
+            );
         });
 
         // ensure stake reported to tellor pallet on oracle consumer parachain
@@ -130,15 +1006,69 @@ mod staking {
             use oracle_consumer_runtime::System;
             System::assert_has_event(
                 tellor::Event::NewStakerReported {
-                    staker: BOB.clone(),
+                    staker: Keyring::Bob.account(),
                     amount: amount.into(),
-                    address: *BALTHAZAR,
+                    address: Keyring::Bob.address(),
                 }
                 .into(),
             );
         });
     }
 
+    #[test]
+    fn deposit_stake_without_sufficient_allowance_reverts() {
+        init_tracing();
+
+        // create trb asset and deploy contracts
+        EvmParachain::execute_with(|| {
+            use parachains::{evm::contracts::*, evm::ALITH};
+            // create asset
+            parachains::evm::create_xctrb_asset();
+            // deploy contracts
+            registry::deploy();
+            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
+            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
+            // init contracts with addresses
+            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // mint and approve less than stake amount
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::{erc20, staking};
+            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(
+                Keyring::Bob.address(),
+                *STAKING_CONTRACT_ADDRESS,
+                amount - 1,
+            );
+            // ensure transferFrom within staking contract reverts, leaving balances unchanged
+            let receipt = staking::try_deposit_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+                Keyring::Bob.account().to_raw_vec(),
+                amount,
+            );
+            assert!(matches!(
+                receipt.exit_reason,
+                pallet_evm::ExitReason::Revert(_)
+            ));
+            assert_eq!(erc20::balance_of(Keyring::Bob.address()), amount.into());
+            assert_eq!(erc20::balance_of(*STAKING_CONTRACT_ADDRESS), 0.into());
+            assert_eq!(
+                erc20::allowance(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS),
+                (amount - 1).into()
+            );
+        });
+    }
+
     #[test]
     fn submit_value_to_consumer_parachain_after_staking_works() {
         init_tracing();
@@ -165,14 +1095,14 @@ mod staking {
         let amount =
             <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
+            use parachains::evm::contracts::{erc20, staking};
             let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
             staking::deposit_parachain_stake(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
         });
@@ -182,13 +1112,17 @@ mod staking {
             use oracle_consumer_runtime::System;
             System::assert_has_event(
                 tellor::Event::NewStakerReported {
-                    staker: BOB.clone(),
+                    staker: Keyring::Bob.account(),
                     amount: amount.into(),
-                    address: *BALTHAZAR,
+                    address: Keyring::Bob.address(),
                 }
                 .into(),
             );
-            parachains::oracle_consumer::submit_value(BOB.clone(), b"hello tellor", b"hey!")
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            )
         });
     }
 
@@ -218,26 +1152,26 @@ mod staking {
         let amount =
             <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
+            use parachains::evm::contracts::{erc20, staking};
             let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
             staking::deposit_parachain_stake(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
             // request withdraw
             staking::request_parachain_stake_withdraw(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
                 amount,
             );
-            staking::assert_stake_withdraw_requested_event(*BALTHAZAR, amount);
+            staking::assert_stake_withdraw_requested_event(Keyring::Bob.address(), amount);
             staking::assert_parachain_stake_withdraw_requested_event(
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
         });
@@ -247,9 +1181,9 @@ mod staking {
             use oracle_consumer_runtime::System;
             System::assert_has_event(
                 tellor::Event::StakeWithdrawRequestReported {
-                    reporter: BOB.clone(),
+                    reporter: Keyring::Bob.account(),
                     amount: amount.into(),
-                    address: *BALTHAZAR,
+                    address: Keyring::Bob.address(),
                 }
                 .into(),
             );
@@ -280,28 +1214,28 @@ mod staking {
 
         // mint, approve, stake trb and request withdrawal from staking contract for oracle consumer parachain
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
+            use parachains::evm::contracts::{erc20, staking};
             let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
             let amount =
                 <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
             staking::deposit_parachain_stake(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
             // request withdraw
             staking::request_parachain_stake_withdraw(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
                 amount,
             );
-            staking::assert_stake_withdraw_requested_event(*BALTHAZAR, amount);
+            staking::assert_stake_withdraw_requested_event(Keyring::Bob.address(), amount);
             staking::assert_parachain_stake_withdraw_requested_event(
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
         });
@@ -315,11 +1249,14 @@ mod staking {
         // withdraw stake from staking contract for oracle consumer parachain
         EvmParachain::execute_with(|| {
             use parachains::evm::contracts::staking;
-            staking::withdraw_parachain_stake(*BALTHAZAR, OracleConsumerParachain::para_id());
-            staking::assert_stake_withdrawn_event(*BALTHAZAR);
+            staking::withdraw_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+            );
+            staking::assert_stake_withdrawn_event(Keyring::Bob.address());
             staking::assert_parachain_stake_withdrawn_event(
                 OracleConsumerParachain::para_id(),
-                *BALTHAZAR,
+                Keyring::Bob.address(),
             );
         });
 
@@ -328,7 +1265,7 @@ mod staking {
             use oracle_consumer_runtime::System;
             System::assert_has_event(
                 tellor::Event::StakeWithdrawnReported {
-                    staker: BOB.clone(),
+                    staker: Keyring::Bob.account(),
                 }
                 .into(),
             );
@@ -336,13 +1273,12 @@ mod staking {
     }
 }
 
-mod autopay {
+mod reporting {
     use super::*;
-    use codec::Compact;
-    use frame_support::bounded_vec;
+    use parachains::reporter::Reporter;
 
     #[test]
-    fn claim_onetime_tip_on_consumer_parachain_works() {
+    fn multiple_reporters_can_report_repeatedly() {
         init_tracing();
 
         // create trb asset and deploy contracts
@@ -363,52 +1299,243 @@ mod autopay {
             parachains::oracle_consumer::register(EvmParachain::para_id())
         });
 
-        // mint, approve and stake trb in staking contract for oracle consumer parachain
+        // stake for two reporters
+        let mut bob = Reporter::from(Keyring::Bob);
+        let mut charlie = Reporter::from(Keyring::Charlie);
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
-            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            let amount =
-                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
-            staking::deposit_parachain_stake(
-                *BALTHAZAR,
-                OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
-                amount,
-            );
+            bob.stake(amount);
+            charlie.stake(amount);
         });
 
-        // add tip, submit value to oracle and then claim
+        let skew = parachains::dispute::clock_skew();
+
+        // report values for the same query from both reporters, with bob reporting twice
+        let (query_id, first) = bob.submit_value(b"hello tellor", b"hey!");
+        let (_, second) = charlie.submit_value(b"hello tellor", b"hi!");
+        let (_, third) = bob.submit_value(b"hello tellor", b"hey again!");
+        // ensure waiting for the reporting lock advanced the clocks of both parachains
+        assert_eq!(parachains::dispute::clock_skew(), skew);
         OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::{RuntimeOrigin, System, Tellor};
-            let query_data = b"hello tellor";
-            let query_id = Keccak256::hash(query_data);
-            let amount = 1_000;
-            // create onetime tip
-            assert_ok!(Tellor::tip(
-                RuntimeOrigin::signed(CHARLIE.clone()),
-                query_id,
-                amount,
-                query_data.to_vec().try_into().unwrap()
-            ));
-            System::assert_has_event(
-                tellor::Event::TipAdded {
-                    query_id,
-                    amount,
-                    query_data: query_data.to_vec().try_into().unwrap(),
-                    tipper: CHARLIE.clone(),
+            use oracle_consumer_runtime::Runtime;
+            assert!(first < second && second < third);
+            // ensure bob waited for reporting lock before reporting again
+            assert!(third - first > <Runtime as tellor::Config>::ReportingLock::get());
+            assert_eq!(Tellor::get_new_value_count_by_query_id(query_id), 3);
+        });
+    }
+}
+
+mod reserve_transfer {
+    use super::*;
+    use parachains::asset_reserve::{self, ALICE};
+
+    #[test]
+    fn staking_trb_reserve_transferred_from_asset_reserve_parachain_works() {
+        init_tracing();
+
+        // create trb asset on asset reserve parachain
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        // additional amount to cover xcm fees, paid in trb
+        let fees = 10u128.pow(18);
+        AssetReserveParachain::execute_with(|| {
+            asset_reserve::create_trb_asset();
+            asset_reserve::mint_trb(ALICE.clone(), amount + fees);
+        });
+
+        // create trb asset and deploy contracts
+        EvmParachain::execute_with(|| {
+            use parachains::{evm::contracts::*, evm::ALITH};
+            // create asset
+            parachains::evm::create_xctrb_asset();
+            // deploy contracts
+            registry::deploy();
+            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
+            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
+            // init contracts with addresses
+            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // reserve transfer trb to staker on evm parachain
+        let since = EvmParachain::execute_with(|| moonbeam_runtime::System::events().len());
+        AssetReserveParachain::execute_with(|| {
+            asset_reserve::reserve_transfer_trb(
+                ALICE.clone(),
+                Keyring::Bob.address(),
+                amount + fees,
+            )
+        });
+
+        // approve and stake received xctrb in staking contract for oracle consumer parachain
+        let balance = EvmParachain::execute_with(|| {
+            use parachains::evm::{
+                contracts::{erc20, staking},
+                fees::executed_xcm_weights,
+                fees::xctrb_fee,
+                xctrb_balance,
+            };
+            // ensure xctrb received, less xcm fees
+            let balance = xctrb_balance(Keyring::Bob.address());
+            let xcm_fee: u128 = executed_xcm_weights(since).into_iter().map(xctrb_fee).sum();
+            assert_eq!(balance, amount + fees - xcm_fee);
+            assert!(balance >= amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
+            staking::deposit_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+                Keyring::Bob.account().to_raw_vec(),
+                amount,
+            );
+            assert_eq!(xctrb_balance(Keyring::Bob.address()), balance - amount);
+            balance
+        });
+
+        // ensure stake reported to tellor pallet on oracle consumer parachain
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::System;
+            System::assert_has_event(
+                tellor::Event::NewStakerReported {
+                    staker: Keyring::Bob.account(),
+                    amount: amount.into(),
+                    address: Keyring::Bob.address(),
+                }
+                .into(),
+            );
+        });
+
+        // request stake withdrawal, advance time beyond lock period and then withdraw stake
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::staking;
+            staking::request_parachain_stake_withdraw(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+                amount,
+            );
+            parachains::evm::advance_time((7 * DAYS) + 1);
+            staking::withdraw_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+            );
+            assert_eq!(
+                parachains::evm::xctrb_balance(Keyring::Bob.address()),
+                balance
+            );
+        });
+
+        // transfer xctrb back to asset reserve parachain
+        let reserve_balance =
+            AssetReserveParachain::execute_with(|| asset_reserve::trb_balance(&ALICE));
+        EvmParachain::execute_with(|| {
+            parachains::evm::transfer_xctrb_to_reserve(
+                Keyring::Bob.address(),
+                ALICE.clone(),
+                balance,
+            )
+        });
+
+        // ensure trb received on asset reserve parachain, less xcm fees
+        AssetReserveParachain::execute_with(|| {
+            let received = asset_reserve::trb_balance(&ALICE) - reserve_balance;
+            assert!(received > 0 && received <= balance);
+            // ensure trb released from sovereign account of evm parachain
+            assert_eq!(
+                asset_reserve::trb_balance(&asset_reserve::sovereign_account_of_evm_parachain()),
+                amount + fees - balance
+            );
+        });
+
+        snapshot::assert_events(
+            "staking_trb_reserve_transferred_from_asset_reserve_parachain_works",
+        );
+    }
+}
+
+mod autopay {
+    use super::*;
+    use codec::Compact;
+    use frame_support::bounded_vec;
+    use parachains::{data_feed::DataFeed, reporter::Reporter};
+
+    #[test]
+    fn claim_onetime_tip_on_consumer_parachain_works() {
+        init_tracing();
+
+        // create trb asset and deploy contracts
+        EvmParachain::execute_with(|| {
+            use parachains::{evm::contracts::*, evm::ALITH};
+            // create asset
+            parachains::evm::create_xctrb_asset();
+            // deploy contracts
+            registry::deploy();
+            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
+            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
+            // init contracts with addresses
+            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // mint, approve and stake trb in staking contract for oracle consumer parachain
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::{erc20, staking};
+            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+            let amount =
+                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
+            staking::deposit_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+                Keyring::Bob.account().to_raw_vec(),
+                amount,
+            );
+        });
+
+        // add tip, submit value to oracle and then claim
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::{RuntimeOrigin, System, Tellor};
+            let spot_price = query::Query::spot_price("trb", "usd");
+            let query_data = spot_price.query_data();
+            let query_id = spot_price.query_id();
+            let amount = 1_000;
+            // create onetime tip
+            assert_ok!(Tellor::tip(
+                RuntimeOrigin::signed(Keyring::Charlie.account()),
+                query_id,
+                amount,
+                query_data.clone().try_into().unwrap()
+            ));
+            System::assert_has_event(
+                tellor::Event::TipAdded {
+                    query_id,
+                    amount,
+                    query_data: query_data.clone().try_into().unwrap(),
+                    tipper: Keyring::Charlie.account(),
                 }
                 .into(),
             );
             // submit value (next block)
             parachains::oracle_consumer::advance_time(1);
-            parachains::oracle_consumer::submit_value(BOB.clone(), query_data, b"hey!");
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                &query_data,
+                &query::encode_price("0.45"),
+            );
             // advance time until claim buffer passed
             parachains::oracle_consumer::advance_time((12 * HOURS) + 1);
             // claim tip
             assert_ok!(Tellor::claim_onetime_tip(
-                RuntimeOrigin::signed(BOB.clone()),
+                RuntimeOrigin::signed(Keyring::Bob.account()),
                 query_id,
                 bounded_vec![Compact(Tellor::time_of_last_new_value().unwrap())]
             ));
@@ -416,7 +1543,7 @@ mod autopay {
                 tellor::Event::OneTimeTipClaimed {
                     query_id,
                     amount,
-                    reporter: BOB.clone(),
+                    reporter: Keyring::Bob.account(),
                 }
                 .into(),
             );
@@ -425,6 +1552,7 @@ mod autopay {
 
     #[test]
     fn claim_tip_on_consumer_parachain_works() {
+        use parachains::balances::{Asset, BalanceTracker};
         init_tracing();
 
         // create trb asset and deploy contracts
@@ -447,25 +1575,30 @@ mod autopay {
 
         // mint, approve and stake trb in staking contract for oracle consumer parachain
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
+            use parachains::evm::contracts::{erc20, staking};
             let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
             let amount =
                 <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
             staking::deposit_parachain_stake(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
         });
 
         // create feed, submit value to oracle and then claim
-        OracleConsumerParachain::execute_with(|| {
+        let balances = BalanceTracker::new()
+            .actor(Keyring::Bob)
+            .actor(Keyring::Charlie)
+            .start();
+        let (amount, reward) = OracleConsumerParachain::execute_with(|| {
             use oracle_consumer_runtime::{AccountId, RuntimeOrigin, System, Tellor};
-            let query_data = b"hello tellor";
-            let query_id = Keccak256::hash(query_data);
+            let spot_price = query::Query::spot_price("eth", "usd");
+            let query_data = spot_price.query_data();
+            let query_id = spot_price.query_id();
             let amount = 10_000;
             // setup data feed
             let reward = 1_000;
@@ -475,7 +1608,7 @@ mod autopay {
             let price_threshold = 0;
             let reward_increase_per_second = 0;
             assert_ok!(Tellor::setup_data_feed(
-                RuntimeOrigin::signed(CHARLIE.clone()),
+                RuntimeOrigin::signed(Keyring::Charlie.account()),
                 query_id,
                 reward,
                 start_time,
@@ -483,7 +1616,7 @@ mod autopay {
                 window,
                 price_threshold,
                 reward_increase_per_second,
-                query_data.to_vec().try_into().unwrap(),
+                query_data.clone().try_into().unwrap(),
                 amount
             ));
             let feed_id = parachains::oracle_consumer::feed_id(
@@ -499,18 +1632,22 @@ mod autopay {
                 tellor::Event::NewDataFeed {
                     query_id,
                     feed_id,
-                    query_data: query_data.to_vec().try_into().unwrap(),
-                    feed_creator: CHARLIE.clone(),
+                    query_data: query_data.clone().try_into().unwrap(),
+                    feed_creator: Keyring::Charlie.account(),
                 }
                 .into(),
             );
             // submit value
-            parachains::oracle_consumer::submit_value(BOB.clone(), query_data, b"hey!");
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                &query_data,
+                &query::encode_price("1800.5"),
+            );
             // advance time until claim buffer passed
             parachains::oracle_consumer::advance_time((12 * HOURS) + 1);
             // claim tip
             assert_ok!(Tellor::claim_tip(
-                RuntimeOrigin::signed(BOB.clone()),
+                RuntimeOrigin::signed(Keyring::Bob.account()),
                 feed_id,
                 query_id,
                 bounded_vec![Compact(Tellor::time_of_last_new_value().unwrap())]
@@ -520,21 +1657,58 @@ mod autopay {
                     feed_id,
                     query_id,
                     amount: reward,
-                    reporter: BOB.clone(),
+                    reporter: Keyring::Bob.account(),
                 }
                 .into(),
             );
+            (amount, reward)
         });
-    }
-}
 
-mod governance {
-    use super::*;
+        // the feed is funded by its creator, with the reward paid to the reporter less the fee
+        // added to staking rewards
+        let fee = reward * <oracle_consumer_runtime::Runtime as tellor::Config>::Fee::get() as u128
+            / 1_000;
+        balances.assert_deltas(&[
+            (Keyring::Charlie.name(), Asset::Consumer, -(amount as i128)),
+            (Keyring::Bob.name(), Asset::Consumer, (reward - fee) as i128),
+        ]);
+    }
 
     #[test]
-    fn begin_dispute_on_consumer_parachain_begins_dispute_on_evm_parachain() {
+    fn staking_and_tipping_change_tracked_balances_by_exact_amounts() {
+        use parachains::{
+            balances::{Asset, BalanceTracker},
+            scenario::Scenario,
+        };
         init_tracing();
 
+        let scenario = Scenario::new().deploy().register();
+
+        // xcTRB minted to the staker is transferred to the staking contract, ignoring gas paid
+        let stakes = BalanceTracker::new()
+            .actor(Keyring::Bob)
+            .address("staking contract", *STAKING_CONTRACT_ADDRESS)
+            .only(&[Asset::XcTrb])
+            .start();
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        let scenario = scenario.stake(Keyring::Bob.address(), Keyring::Bob.account(), amount);
+        stakes.assert_deltas(&[("staking contract", Asset::XcTrb, amount as i128)]);
+
+        // tips are paid in the native token of the consumer parachain, with no other balance of the
+        // tipper changing on any chain
+        let tips = BalanceTracker::new().actor(Keyring::Dave).start();
+        let tip = 1_000;
+        scenario.tip(
+            Keyring::Dave.account(),
+            &query::Query::spot_price("eth", "usd"),
+            tip,
+        );
+        tips.assert_deltas(&[(Keyring::Dave.name(), Asset::Consumer, -(tip as i128))]);
+    }
+
+    // deploys contracts, registers the oracle consumer parachain and stakes for bob and dave
+    fn setup_reporters() -> (Reporter, Reporter) {
         // create trb asset and deploy contracts
         EvmParachain::execute_with(|| {
             use parachains::{evm::contracts::*, evm::ALITH};
@@ -546,7 +1720,6 @@ mod governance {
             governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
             // init contracts with addresses
             staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
-            governance::init(*STAKING_CONTRACT_ADDRESS);
         });
 
         // register oracle consumer parachain with contracts on evm parachain via tellor pallet
@@ -554,46 +1727,142 @@ mod governance {
             parachains::oracle_consumer::register(EvmParachain::para_id())
         });
 
-        // mint, approve and stake trb in staking contract for oracle consumer parachain
-        let amount =
-            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        // stake for reporters
+        let bob = Reporter::from(Keyring::Bob);
+        let dave = Reporter::from(Keyring::Dave);
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
-            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
-            staking::deposit_parachain_stake(
-                *BALTHAZAR,
-                OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
-                amount,
-            );
+            let amount =
+                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+            bob.stake(amount);
+            dave.stake(amount);
         });
+        (bob, dave)
+    }
+
+    #[test]
+    fn data_feed_rewards_first_report_within_each_window() {
+        init_tracing();
+        let (mut bob, mut dave) = setup_reporters();
+
+        let skew = parachains::dispute::clock_skew();
+
+        // setup feed with funds for two rewards
+        let mut feed = DataFeed::new(&query::Query::spot_price("trb", "usd"), 1_000, DAYS, HOURS);
+        feed.setup(Keyring::Charlie.account(), 2_000);
+
+        // first report within the window of each interval is eligible
+        let first = feed.report(&mut bob, 0, 60, &query::encode_price("15.0"));
+        let second = feed.report(&mut dave, 0, 120, &query::encode_price("15.1"));
+        let third = feed.report(&mut dave, 1, 60, &query::encode_price("15.2"));
+        // report outside window, without a price threshold
+        let fourth = feed.report(&mut bob, 2, HOURS + 60, &query::encode_price("15.3"));
+
+        assert_eq!(feed.claim(Keyring::Bob.account(), &[first]), 1_000);
+        feed.assert_not_claimable(Keyring::Dave.account(), second);
+        feed.assert_not_claimable(Keyring::Bob.account(), fourth);
+        assert_eq!(feed.claim(Keyring::Dave.account(), &[third]), 1_000);
+        assert_eq!(feed.balance(), 0);
+
+        // re-fund feed and report again in a later interval
+        feed.fund(Keyring::Charlie.account(), 1_500);
+        let now = OracleConsumerParachain::execute_with(|| {
+            <Tellor as tellor::UsingTellor<oracle_consumer_runtime::AccountId>>::now()
+        });
+        let interval = (now - feed.start_time) / DAYS + 1;
+        let fifth = feed.report(&mut bob, interval, 0, &query::encode_price("15.4"));
+        assert_eq!(feed.claim(Keyring::Bob.account(), &[fifth]), 1_000);
+        assert_eq!(feed.balance(), 500);
+        // ensure advancing through intervals and claim buffers kept the clocks of both parachains
+        // in sync
+        assert_eq!(parachains::dispute::clock_skew(), skew);
+    }
+
+    #[test]
+    fn data_feed_applies_price_threshold_and_reward_increase() {
+        init_tracing();
+        let (mut bob, mut dave) = setup_reporters();
+
+        // setup feed with a 1% price threshold, with reward increasing by 1 per second
+        let mut feed = DataFeed::new(&query::Query::spot_price("trb", "usd"), 1_000, DAYS, HOURS)
+            .price_threshold(100)
+            .reward_increase_per_second(1);
+        feed.setup(Keyring::Charlie.account(), 3_000);
+
+        // reward increases with time since start of interval when within window
+        let first = feed.report(&mut bob, 0, 600, &query::encode_price("15.0"));
+        assert_eq!(
+            feed.reward_at(first),
+            1_000 + (first - feed.start_time) as u128
+        );
+        // reports outside window are eligible when price changes by more than threshold
+        let second = feed.report(&mut dave, 0, HOURS + 60, &query::encode_price("16.5"));
+        let third = feed.report(&mut bob, 1, HOURS + 60, &query::encode_price("16.6"));
+
+        let claimed = feed.claim(Keyring::Bob.account(), &[first]);
+        assert_eq!(claimed, 1_000 + (first - feed.start_time) as u128);
+        assert_eq!(feed.claim(Keyring::Dave.account(), &[second]), 1_000);
+        feed.assert_not_claimable(Keyring::Bob.account(), third);
+
+        // rewards are limited to the remaining balance
+        let fourth = feed.report(&mut dave, 3, 0, &query::encode_price("18.0"));
+        let remaining = feed.balance();
+        assert_eq!(remaining, 3_000 - 1_000 - claimed);
+        assert_eq!(feed.claim(Keyring::Dave.account(), &[fourth]), remaining);
+        assert_eq!(feed.balance(), 0);
+    }
+}
+
+mod governance {
+    use super::*;
+    use parachains::{dispute::PARACHAIN_VOTING_CUT_OFF, scenario::Scenario};
+    use query::{encode_price, Query};
+
+    // deploys and initialises the contracts, registers the oracle consumer parachain and stakes the
+    // minimum stake amount for Bob as Balthazar
+    fn staked() -> (Scenario, u128) {
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        let scenario = Scenario::new().deploy().register().stake(
+            Keyring::Bob.address(),
+            Keyring::Bob.account(),
+            amount,
+        );
+        (scenario, amount)
+    }
+
+    #[test]
+    fn begin_dispute_on_consumer_parachain_begins_dispute_on_evm_parachain() {
+        init_tracing();
+
+        let (_, amount) = staked();
 
         // submit value to oracle consumer parachain and then begin dispute of reported value
         let (query_id, timestamp) = OracleConsumerParachain::execute_with(|| {
             use oracle_consumer_runtime::{Runtime, RuntimeOrigin, System, Tellor};
             // submit value
-            let (query_id, timestamp) =
-                parachains::oracle_consumer::submit_value(BOB.clone(), b"hello tellor", b"hey!");
+            let (query_id, timestamp) = parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            );
             // begin dispute
             assert_ok!(Tellor::begin_dispute(
-                RuntimeOrigin::signed(DAVE.clone()),
+                RuntimeOrigin::signed(Keyring::Dave.account()),
                 query_id,
                 timestamp,
-                Some(*DOROTHY)
+                Some(Keyring::Dave.address())
             ));
-            let dispute_id = Keccak256::hash(&ethabi::encode(&[
-                ethabi::Token::Uint(<Runtime as tellor::Config>::ParachainId::get().into()),
-                ethabi::Token::FixedBytes(query_id.0.into()),
-                ethabi::Token::Uint(timestamp.into()),
-            ]));
+            let dispute_id = parachains::dispute::dispute_id(
+                <Runtime as tellor::Config>::ParachainId::get(),
+                query_id,
+                timestamp,
+            );
             System::assert_has_event(
                 tellor::Event::NewDispute {
                     dispute_id,
                     query_id,
                     timestamp,
-                    reporter: BOB.clone(),
+                    reporter: Keyring::Bob.account(),
                 }
                 .into(),
             );
@@ -615,7 +1884,7 @@ mod governance {
             // ensure ParachainReporterSlashed event emitted by parachain staking contract
             contracts::staking::assert_parachain_reporter_slashed_event(
                 OracleConsumerParachain::para_id(),
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 *GOVERNANCE_CONTRACT_ADDRESS,
                 amount,
             );
@@ -624,7 +1893,7 @@ mod governance {
                 OracleConsumerParachain::para_id(),
                 query_id.0.to_vec(),
                 timestamp,
-                *BALTHAZAR,
+                Keyring::Bob.address(),
             );
         });
 
@@ -633,7 +1902,7 @@ mod governance {
             use oracle_consumer_runtime::System;
             System::assert_has_event(
                 tellor::Event::SlashReported {
-                    reporter: BOB.clone(),
+                    reporter: Keyring::Bob.account(),
                     amount: amount.into(),
                 }
                 .into(),
@@ -641,97 +1910,64 @@ mod governance {
         });
     }
 
+    // reports a value which is then disputed by Dave and voted on by both Dave, as a user having
+    // tipped the query, and Bob, as the reporter
+    fn voted(tip: u128) -> Scenario {
+        let (scenario, _) = staked();
+        let query = Query::spot_price("eth", "usd");
+        scenario
+            .tip(Keyring::Dave.account(), &query, tip)
+            .report(&query, &encode_price("1800.0"))
+            .dispute(Keyring::Dave.account(), Keyring::Dave.address())
+            .vote(&[
+                (Keyring::Dave.account(), Some(true)),
+                (Keyring::Bob.account(), Some(false)),
+            ])
+    }
+
     #[test]
     fn on_initialize_hook_on_consumer_parachain_sends_votes_to_evm_parachain() {
         init_tracing();
 
-        // create trb asset and deploy contracts
-        EvmParachain::execute_with(|| {
-            use parachains::{evm::contracts::*, evm::ALITH};
-            // create asset
-            parachains::evm::create_xctrb_asset();
-            // deploy contracts
-            registry::deploy();
-            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
-            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
-            // init contracts with addresses
-            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
-            governance::init(*STAKING_CONTRACT_ADDRESS);
-        });
+        // create tip to be able to have vote counted as user, then report, dispute and vote
+        let tip = 1_000_000_000_000;
+        let scenario = voted(tip);
 
-        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
-        OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::register(EvmParachain::para_id())
-        });
+        // advance time until parachain voting cut-off, sending votes via on_initialize hook
+        let scenario = scenario.advance(PARACHAIN_VOTING_CUT_OFF + 1);
 
-        // mint, approve and stake trb in staking contract for oracle consumer parachain
+        // ensure governance contract called and events emitted on evm parachain
+        let dispute_id = scenario.current_dispute().id;
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
-            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            let amount =
-                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
-            staking::deposit_parachain_stake(
-                *BALTHAZAR,
-                OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
-                amount,
-            );
+            use parachains::evm::*;
+            // ensure governance contract called (via pallet derivative account on evm parachain)
+            contracts::governance::assert_executed(*PALLET_DERIVATIVE_ACCOUNT);
+            // ensure ParachainVoted event emitted by parachain governance contract
+            contracts::governance::assert_parachain_voted_event(dispute_id, tip, 0, 0, 0, 1, 0);
         });
+    }
 
-        // create tip to be able to have vote counted as user
+    #[test]
+    fn send_votes_from_consumer_parachain_to_evm_parachain_works() {
+        init_tracing();
+
+        // create tip to be able to have vote counted as user, then report, dispute and vote
         let tip = 1_000_000_000_000;
-        let query_data = b"hello tellor";
+        let scenario = voted(tip);
+
+        // advance time until parachain voting cut-off and send votes via extrinsic, rather than
+        // the on_initialize hook
         OracleConsumerParachain::execute_with(|| {
             use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
-            assert_ok!(Tellor::tip(
-                RuntimeOrigin::signed(DAVE.clone()),
-                Keccak256::hash(query_data.as_slice()),
-                tip,
-                query_data.to_vec().try_into().unwrap()
-            ));
-        });
-
-        // submit value to oracle consumer parachain, begin dispute and cast votes
-        let dispute_id = OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::{Runtime, RuntimeOrigin, Tellor};
-            // submit value
-            let (query_id, timestamp) =
-                parachains::oracle_consumer::submit_value(BOB.clone(), query_data, b"hey!");
-            // begin dispute
-            assert_ok!(Tellor::begin_dispute(
-                RuntimeOrigin::signed(DAVE.clone()),
-                query_id,
-                timestamp,
-                Some(*DOROTHY)
-            ));
-            let dispute_id = Keccak256::hash(&ethabi::encode(&[
-                ethabi::Token::Uint(<Runtime as tellor::Config>::ParachainId::get().into()),
-                ethabi::Token::FixedBytes(query_id.0.into()),
-                ethabi::Token::Uint(timestamp.into()),
-            ]));
-            // cast votes
-            assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(DAVE.clone()),
-                dispute_id,
-                Some(true) // for
-            ));
-            assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(BOB.clone()),
-                dispute_id,
-                Some(false) // against
+            parachains::oracle_consumer::advance_time(PARACHAIN_VOTING_CUT_OFF + 1);
+            assert_ok!(Tellor::send_votes(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                5
             ));
-            dispute_id
-        });
-
-        // advance time until parachain voting cut-off
-        OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::advance_time((11 * HOURS) + 1);
-            Tellor::on_initialize(0)
         });
 
         // ensure governance contract called and events emitted on evm parachain
+        let dispute_id = scenario.current_dispute().id;
         EvmParachain::execute_with(|| {
             use parachains::evm::*;
             // ensure governance contract called (via pallet derivative account on evm parachain)
@@ -742,43 +1978,186 @@ mod governance {
     }
 
     #[test]
-    fn send_votes_from_consumer_parachain_to_evm_parachain_works() {
+    fn tally_votes_on_evm_parachain_reports_result_to_consumer_parachain() {
         init_tracing();
 
-        // create trb asset and deploy contracts
-        EvmParachain::execute_with(|| {
-            use parachains::{evm::contracts::*, evm::ALITH};
-            // create asset
-            parachains::evm::create_xctrb_asset();
-            // deploy contracts
-            registry::deploy();
-            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
-            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
-            // init contracts with addresses
-            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
-            governance::init(*STAKING_CONTRACT_ADDRESS);
+        // create tip to be able to have vote counted as user, then report, dispute and vote
+        let scenario = voted(1_000_000_000_000);
+
+        // advance time until parachain voting cut-off and then until end of voting period, before
+        // tallying votes on evm parachain, which reports the result to the oracle consumer
+        // parachain
+        let scenario = scenario
+            .advance(PARACHAIN_VOTING_CUT_OFF + 1)
+            .advance((1 * DAYS) + 1)
+            .tally(VoteResult::Invalid);
+
+        // ensure tally reported to tellor pallet on oracle consumer parachain
+        let dispute_id = scenario.current_dispute().id;
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::System;
+            System::assert_has_event(
+                tellor::Event::VoteTallied {
+                    dispute_id,
+                    result: VoteResult::Invalid,
+                    initiator: Keyring::Dave.account(),
+                    reporter: Keyring::Bob.account(),
+                }
+                .into(),
+            );
         });
+    }
 
-        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+    #[test]
+    fn execute_vote_on_evm_parachain_reports_to_consumer_parachain() {
+        init_tracing();
+
+        // create tip to be able to have vote counted as user, then report, dispute and vote
+        let scenario = voted(1_000_000_000_000);
+
+        // advance time until end of voting period, tally votes and then execute vote on evm
+        // parachain once tally period has elapsed
+        let scenario = scenario
+            .advance(PARACHAIN_VOTING_CUT_OFF + 1)
+            .advance((1 * DAYS) + 1)
+            .tally(VoteResult::Invalid)
+            .execute();
+
+        // ensure executed vote reported to tellor pallet on oracle consumer parachain
+        let dispute_id = scenario.current_dispute().id;
         OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::register(EvmParachain::para_id())
+            use oracle_consumer_runtime::System;
+            System::assert_has_event(
+                tellor::Event::VoteExecuted {
+                    dispute_id,
+                    result: VoteResult::Invalid,
+                }
+                .into(),
+            );
         });
+    }
 
-        // mint, approve and stake trb in staking contract for oracle consumer parachain
+    #[test]
+    fn begin_parachain_dispute_directly_on_evm_parachain_slashes_reporter() {
+        init_tracing();
+
+        let (scenario, amount) = staked();
+
+        // report value on oracle consumer parachain, then begin dispute of reported value directly
+        // on evm parachain as parachain owner, within the reporting lock
+        let value = encode_price("1800.0");
+        let scenario = scenario.report(&Query::spot_price("eth", "usd"), &value);
+        let (query_id, timestamp) = scenario.last_report();
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
-            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            let amount =
-                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
-            staking::deposit_parachain_stake(
-                *BALTHAZAR,
+            use parachains::evm::contracts::{governance, staking};
+            governance::begin_parachain_dispute(
+                query_id,
+                timestamp,
+                value.clone(),
+                Keyring::Bob.address(),
+                Keyring::Dave.address(),
+                amount / 10,
+                amount,
+            );
+            // ensure reporter slashed and dispute recorded
+            staking::assert_parachain_reporter_slashed_event(
+                OracleConsumerParachain::para_id(),
+                Keyring::Bob.address(),
+                *GOVERNANCE_CONTRACT_ADDRESS,
+                amount,
+            );
+            governance::assert_new_parachain_dispute_event(
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                query_id.0.to_vec(),
+                timestamp,
+                Keyring::Bob.address(),
+            );
+            let dispute_id = parachains::dispute::dispute_id(
+                OracleConsumerParachain::para_id(),
+                query_id,
+                timestamp,
+            );
+            assert_eq!(governance::get_vote_rounds(dispute_id), 1.into());
+        });
+
+        // ensure slash reported to tellor pallet on oracle consumer parachain
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::System;
+            System::assert_has_event(
+                tellor::Event::SlashReported {
+                    reporter: Keyring::Bob.account(),
+                    amount: amount.into(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn begin_parachain_dispute_after_tally_begins_new_vote_round() {
+        init_tracing();
+
+        let (scenario, amount) = staked();
+
+        // report value on oracle consumer parachain, then begin dispute of reported value directly
+        // on evm parachain as parachain owner, within the reporting lock
+        let value = encode_price("1800.0");
+        let scenario = scenario.report(&Query::spot_price("eth", "usd"), &value);
+        let (query_id, timestamp) = scenario.last_report();
+        let dispute_fee = amount / 10;
+        let dispute_id = parachains::dispute::dispute_id(
+            OracleConsumerParachain::para_id(),
+            query_id,
+            timestamp,
+        );
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::governance;
+            governance::begin_parachain_dispute(
+                query_id,
+                timestamp,
+                value.clone(),
+                Keyring::Bob.address(),
+                Keyring::Dave.address(),
+                dispute_fee,
+                amount,
+            );
+            assert_eq!(governance::get_vote_rounds(dispute_id), 1.into());
+        });
+
+        // advance time until voting period of first round ends and then tally votes
+        EvmParachain::execute_with(|| {
+            use parachains::evm::{contracts::governance, ALITH};
+            parachains::evm::advance_time((1 * DAYS) + 1);
+            governance::tally_votes(*ALITH, dispute_id);
+            governance::assert_vote_tallied_event(
+                dispute_id,
+                VoteResult::Invalid as u8,
+                Keyring::Dave.address(),
+                Keyring::Bob.address(),
+            );
+        });
+
+        // dispute again, with a doubled dispute fee, which begins a new vote round
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::governance;
+            governance::begin_parachain_dispute(
+                query_id,
+                timestamp,
+                value.clone(),
+                Keyring::Bob.address(),
+                Keyring::Dave.address(),
+                dispute_fee * 2,
                 amount,
             );
+            assert_eq!(governance::get_vote_rounds(dispute_id), 2.into());
         });
+    }
+
+    #[test]
+    fn tally_votes_combines_token_holder_team_and_parachain_votes() {
+        init_tracing();
+
+        let (_, amount) = staked();
 
         // create tip to be able to have vote counted as user
         let tip = 1_000_000_000_000;
@@ -786,7 +2165,7 @@ mod governance {
         OracleConsumerParachain::execute_with(|| {
             use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
             assert_ok!(Tellor::tip(
-                RuntimeOrigin::signed(DAVE.clone()),
+                RuntimeOrigin::signed(Keyring::Dave.account()),
                 Keccak256::hash(query_data.as_slice()),
                 tip,
                 query_data.to_vec().try_into().unwrap()
@@ -797,49 +2176,1149 @@ mod governance {
         let dispute_id = OracleConsumerParachain::execute_with(|| {
             use oracle_consumer_runtime::{Runtime, RuntimeOrigin, Tellor};
             // submit value
-            let (query_id, timestamp) =
-                parachains::oracle_consumer::submit_value(BOB.clone(), query_data, b"hey!");
+            let (query_id, timestamp) = parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                query_data,
+                b"hey!",
+            );
             // begin dispute
             assert_ok!(Tellor::begin_dispute(
-                RuntimeOrigin::signed(DAVE.clone()),
+                RuntimeOrigin::signed(Keyring::Dave.account()),
                 query_id,
                 timestamp,
-                Some(*DOROTHY)
+                Some(Keyring::Dave.address())
             ));
-            let dispute_id = Keccak256::hash(&ethabi::encode(&[
-                ethabi::Token::Uint(<Runtime as tellor::Config>::ParachainId::get().into()),
-                ethabi::Token::FixedBytes(query_id.0.into()),
-                ethabi::Token::Uint(timestamp.into()),
-            ]));
+            let dispute_id = parachains::dispute::dispute_id(
+                <Runtime as tellor::Config>::ParachainId::get(),
+                query_id,
+                timestamp,
+            );
             // cast votes
             assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(DAVE.clone()),
+                RuntimeOrigin::signed(Keyring::Dave.account()),
                 dispute_id,
                 Some(true) // for
             ));
             assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(BOB.clone()),
+                RuntimeOrigin::signed(Keyring::Bob.account()),
                 dispute_id,
                 Some(false) // against
             ));
-            // advance time until parachain voting cut-off
-            parachains::oracle_consumer::advance_time((11 * HOURS) + 1);
-            assert_ok!(Tellor::send_votes(RuntimeOrigin::signed(DAVE.clone()), 5));
             dispute_id
         });
 
-        // ensure governance contract called and events emitted on evm parachain
-        EvmParachain::execute_with(|| {
-            use parachains::evm::*;
-            // ensure governance contract called (via pallet derivative account on evm parachain)
-            contracts::governance::assert_executed(*PALLET_DERIVATIVE_ACCOUNT);
-            // ensure ParachainVoted event emitted by parachain governance contract
-            contracts::governance::assert_parachain_voted_event(dispute_id, tip, 0, 0, 0, 1, 0);
-        });
+        // cast votes on evm parachain as token holder and team multisig
+        EvmParachain::execute_with(|| {
+            use parachains::evm::{contracts::*, ALITH};
+            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+            staking::mint(asset, Keyring::Charlie.address(), amount);
+            // token holder
+            governance::vote(Keyring::Charlie.address(), dispute_id, true, false);
+            governance::assert_voted_event(dispute_id, true, Keyring::Charlie.address(), false);
+            // team multisig
+            governance::vote(*ALITH, dispute_id, true, false);
+            governance::assert_voted_event(dispute_id, true, *ALITH, false);
+            assert!(governance::did_vote(dispute_id, Keyring::Charlie.address()));
+            assert!(governance::did_vote(dispute_id, *ALITH));
+            assert!(!governance::did_vote(dispute_id, Keyring::Dave.address()));
+        });
+
+        // advance time until parachain voting cut-off
+        EvmParachain::execute_with(|| parachains::evm::advance_time((11 * HOURS) + 1));
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::advance_time((11 * HOURS) + 1);
+            // send votes via on_initialize hook
+            Tellor::on_initialize(0)
+        });
+        EvmParachain::execute_with(|| {
+            use parachains::evm::*;
+            contracts::governance::assert_parachain_voted_event(dispute_id, tip, 0, 0, 0, 1, 0);
+        });
+
+        // advance time until voting cut-off
+        EvmParachain::execute_with(|| parachains::evm::advance_time((1 * DAYS) + 1));
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::advance_time((1 * DAYS) + 1);
+            // send votes via on_initialize hook
+            Tellor::on_initialize(0)
+        });
+
+        // tally votes on evm parachain: token holders, users (tips) and team for, reporters against
+        EvmParachain::execute_with(|| {
+            use parachains::evm::*;
+            contracts::governance::tally_votes(*ALITH, dispute_id);
+            contracts::governance::assert_vote_tallied_event(
+                dispute_id,
+                VoteResult::Passed as u8,
+                Keyring::Dave.address(),
+                Keyring::Bob.address(),
+            );
+        });
+
+        // ensure tally reported to tellor pallet on oracle consumer parachain
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::System;
+            System::assert_has_event(
+                tellor::Event::VoteTallied {
+                    dispute_id,
+                    result: VoteResult::Passed,
+                    initiator: Keyring::Dave.account(),
+                    reporter: Keyring::Bob.account(),
+                }
+                .into(),
+            );
+        });
+    }
+
+    #[test]
+    fn dispute_helper_tracks_rounds_fees_and_cross_chain_events() {
+        use parachains::{
+            balances::{Asset, BalanceTracker},
+            dispute::Dispute,
+        };
+        init_tracing();
+
+        let (_, amount) = staked();
+
+        // submit value to oracle consumer parachain and then begin dispute of reported value
+        let (query_id, timestamp) = OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            )
+        });
+        // only the dispute fee of the initiator changes, with the slashed stake held by the contracts
+        let track = || {
+            BalanceTracker::new()
+                .actor(Keyring::Bob)
+                .actor(Keyring::Dave)
+                .start()
+        };
+        let tracker = track();
+        let mut dispute = Dispute::begin(
+            Keyring::Dave.account(),
+            Keyring::Dave.address(),
+            query_id,
+            timestamp,
+        );
+        assert_eq!(dispute.round(), 1);
+        assert_eq!(dispute.reporter, Keyring::Bob.account());
+        assert!(dispute.fees[0] > 0);
+        tracker.assert_deltas(&[(
+            Keyring::Dave.name(),
+            Asset::Consumer,
+            -(dispute.fees[0] as i128),
+        )]);
+        dispute.assert_begun_on_evm_parachain(Keyring::Bob.address(), amount);
+
+        // cast votes and advance through voting period before tallying
+        dispute.vote(&[
+            (Keyring::Dave.account(), Some(true)),
+            (Keyring::Bob.account(), Some(false)),
+        ]);
+        dispute.advance_to_parachain_voting_cut_off();
+        dispute.advance_to_end_of_voting_period();
+        dispute.tally(
+            VoteResult::Invalid,
+            Keyring::Dave.address(),
+            Keyring::Bob.address(),
+        );
+
+        // dispute again, which begins a new round with a doubled dispute fee
+        let tracker = track();
+        dispute.begin_next_round(Keyring::Dave.address());
+        assert_eq!(dispute.round(), 2);
+        assert_eq!(dispute.fees[1], dispute.fees[0] * 2);
+        tracker.assert_deltas(&[(
+            Keyring::Dave.name(),
+            Asset::Consumer,
+            -(dispute.fees[1] as i128),
+        )]);
+        assert_eq!(dispute.total_fees(), dispute.fees[0] * 3);
+        dispute.assert_begun_on_evm_parachain(Keyring::Bob.address(), amount);
+    }
+
+    #[test]
+    fn dispute_escalates_through_all_vote_rounds_before_execution() {
+        use parachains::dispute::Dispute;
+        use tellor::MAX_VOTE_ROUNDS;
+        init_tracing();
+
+        let (_, amount) = staked();
+
+        // create tip to have votes counted as user and submit value
+        let tip = 1_000_000_000_000;
+        let query_data = b"hello tellor";
+        let (query_id, timestamp) = OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
+            assert_ok!(Tellor::tip(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                Keccak256::hash(query_data.as_slice()),
+                tip,
+                query_data.to_vec().try_into().unwrap()
+            ));
+            parachains::oracle_consumer::submit_value(Keyring::Bob.account(), query_data, b"hey!")
+        });
+
+        // dispute value, with the fee of each subsequent round doubling until capped at the stake
+        // amount, of which the initial dispute fee is a tenth
+        let mut dispute = Dispute::begin(
+            Keyring::Dave.account(),
+            Keyring::Dave.address(),
+            query_id,
+            timestamp,
+        );
+        dispute.assert_begun_on_evm_parachain(Keyring::Bob.address(), amount);
+        let base_fee = dispute.fees[0];
+        let stake_amount = base_fee * 10;
+        let expected_fee = |round: u8| stake_amount.min(base_fee * 2u128.pow(round as u32 - 1));
+
+        // fund disputer with exactly the fees of the remaining rounds
+        let remaining_fees: u128 = (2..=MAX_VOTE_ROUNDS).map(&expected_fee).sum();
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::Balances;
+            parachains::oracle_consumer::set_balance(
+                Keyring::Dave.account(),
+                Balances::free_balance(&Keyring::Dave.account()) + remaining_fees,
+            );
+        });
+
+        // dispute value through each vote round, alternating the outcome of the votes
+        for round in 1..=MAX_VOTE_ROUNDS {
+            assert_eq!(dispute.round(), round);
+            assert_eq!(dispute.fees[round as usize - 1], expected_fee(round));
+            let supports = round % 2 == 1;
+            dispute.vote(&[
+                (Keyring::Dave.account(), Some(supports)),
+                (Keyring::Bob.account(), Some(!supports)),
+            ]);
+            dispute.advance_to_parachain_voting_cut_off();
+            // ensure votes of round aggregated on evm parachain
+            EvmParachain::execute_with(|| {
+                use parachains::evm::contracts::governance;
+                if supports {
+                    governance::assert_parachain_voted_event(dispute.id, tip, 0, 0, 0, 1, 0);
+                } else {
+                    governance::assert_parachain_voted_event(dispute.id, 0, tip, 0, 1, 0, 0);
+                }
+            });
+            dispute.advance_to_end_of_voting_period();
+            dispute.tally(
+                VoteResult::Invalid,
+                Keyring::Dave.address(),
+                Keyring::Bob.address(),
+            );
+
+            if round < MAX_VOTE_ROUNDS {
+                dispute.begin_next_round(Keyring::Dave.address());
+                dispute.assert_begun_on_evm_parachain(Keyring::Bob.address(), amount);
+            }
+        }
+        assert_eq!(dispute.total_fees(), base_fee + remaining_fees);
+        // ensure the fee was capped in the final rounds
+        assert_eq!(expected_fee(MAX_VOTE_ROUNDS), stake_amount);
+
+        // ensure no further rounds can be begun on the oracle consumer parachain
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
+            assert!(Tellor::begin_dispute(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                query_id,
+                timestamp,
+                Some(Keyring::Dave.address())
+            )
+            .is_err());
+        });
+
+        // execute vote after final round
+        dispute.execute(VoteResult::Invalid);
+        assert_eq!(dispute.fees.len(), MAX_VOTE_ROUNDS as usize);
+    }
+
+    #[test]
+    fn scenario_runs_dispute_from_report_to_execution() {
+        init_tracing();
+
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        let query = Query::spot_price("eth", "usd");
+        let scenario = Scenario::new()
+            .deploy()
+            .register()
+            .stake(Keyring::Bob.address(), Keyring::Bob.account(), amount)
+            .report(&query, &encode_price("1800.0"))
+            .dispute(Keyring::Dave.account(), Keyring::Dave.address())
+            .vote(&[
+                (Keyring::Dave.account(), Some(true)),
+                (Keyring::Bob.account(), Some(false)),
+            ])
+            .advance((11 * HOURS) + 1)
+            .advance((1 * DAYS) + 1)
+            .tally(VoteResult::Invalid)
+            .execute();
+
+        // ensure dispute was of reported value
+        let (query_id, timestamp) = scenario.last_report();
+        assert_eq!(query_id, query.query_id());
+        let dispute = scenario.current_dispute();
+        assert_eq!(
+            dispute.id,
+            parachains::dispute::dispute_id(
+                OracleConsumerParachain::para_id(),
+                query_id,
+                timestamp
+            )
+        );
+        assert_eq!(dispute.round(), 1);
+
+        snapshot::assert_events("scenario_runs_dispute_from_report_to_execution");
+    }
+}
+
+mod fees {
+    use super::*;
+    use parachains::evm::{fees, transaction::balance_of};
+
+    // executes on the consumer parachain, returning the fee charged to the pallet derivative account
+    // on the evm parachain for the resulting xcm, whilst ensuring the pallet account is not charged
+    fn charged<R>(execute: impl FnOnce() -> R) -> (R, u128) {
+        use parachains::oracle_consumer::pallet_account;
+        let (balance, since) = EvmParachain::execute_with(|| {
+            (
+                balance_of(*PALLET_DERIVATIVE_ACCOUNT),
+                moonbeam_runtime::System::events().len(),
+            )
+        });
+        let pallet_balance = OracleConsumerParachain::execute_with(|| {
+            oracle_consumer_runtime::Balances::free_balance(pallet_account())
+        });
+        let result = OracleConsumerParachain::execute_with(|| {
+            let result = execute();
+            // no delivery fees are charged for sending xcm
+            assert_eq!(
+                oracle_consumer_runtime::Balances::free_balance(pallet_account()),
+                pallet_balance
+            );
+            result
+        });
+        let fee = EvmParachain::execute_with(|| {
+            fees::assert_charged_for_executed_xcm(*PALLET_DERIVATIVE_ACCOUNT, balance, since)
+        });
+        (result, fee)
+    }
+
+    // executes on the chains, returning the fee charged on the consumer parachain to the accounts of
+    // the staking and governance contracts for the resulting reports
+    fn reported<R>(execute: impl FnOnce() -> R) -> (R, u128) {
+        use oracle_consumer_runtime::{Balances, System};
+        use parachains::oracle_consumer::{contract_account, executed_xcm_weights, native_fee};
+        let accounts = [
+            contract_account(*STAKING_CONTRACT_ADDRESS),
+            contract_account(*GOVERNANCE_CONTRACT_ADDRESS),
+        ];
+        let balances = || -> u128 { accounts.iter().map(Balances::free_balance).sum() };
+        let (balance, since) =
+            OracleConsumerParachain::execute_with(|| (balances(), System::events().len()));
+        let result = execute();
+        let fee = OracleConsumerParachain::execute_with(|| {
+            let weights = executed_xcm_weights(since);
+            assert!(!weights.is_empty(), "no report executed");
+            let fee = weights.into_iter().map(native_fee).sum();
+            assert_eq!(balance - balances(), fee);
+            fee
+        });
+        (result, fee)
+    }
+
+    #[test]
+    fn xctrb_paid_message_from_tellor_pallet_is_charged_according_to_fee_model() {
+        use parachains::balances::{Asset, BalanceTracker};
+        use parachains::evm::{contracts::staking, xctrb_balance, XCTRB_ASSET_ID};
+        use parachains::oracle_consumer::send_to_evm_parachain;
+        init_tracing();
+
+        // fund pallet derivative account with xcTRB
+        let amount = 10u128.pow(18);
+        let since = EvmParachain::execute_with(|| {
+            parachains::evm::create_xctrb_asset();
+            staking::mint(*XCTRB_ASSET_ID, *PALLET_DERIVATIVE_ACCOUNT, amount);
+            moonbeam_runtime::System::events().len()
+        });
+        let balances = BalanceTracker::new()
+            .address("pallet derivative account", *PALLET_DERIVATIVE_ACCOUNT)
+            .start();
+
+        // send message as the tellor pallet, paying for execution in xcTRB and depositing the
+        // surplus back into the pallet derivative account
+        OracleConsumerParachain::execute_with(|| {
+            let payment: MultiAsset = (fees::xctrb_asset(), amount).into();
+            send_to_evm_parachain(Xcm(vec![
+                WithdrawAsset(payment.clone().into()),
+                BuyExecution {
+                    fees: payment,
+                    weight_limit: Unlimited,
+                },
+                RefundSurplus,
+                DepositAsset {
+                    assets: Wild(AllCounted(1)),
+                    beneficiary: MultiLocation::new(
+                        0,
+                        X1(AccountKey20 {
+                            network: None,
+                            key: PALLET_DERIVATIVE_ACCOUNT.0,
+                        }),
+                    ),
+                },
+            ]));
+        });
+
+        // ensure charged exactly the xcTRB fee for the weight executed, which is equivalent to the
+        // native fee at the assumed TRB price (less rounding)
+        let fee = EvmParachain::execute_with(|| {
+            assert!(fees::failed_xcm_errors(since).is_empty());
+            let [weight]: [_; 1] = fees::executed_xcm_weights(since)
+                .try_into()
+                .expect("a single message executed");
+            let fee = fees::xctrb_fee(weight);
+            assert!(fee > 0);
+            assert_eq!(xctrb_balance(*PALLET_DERIVATIVE_ACCOUNT), amount - fee);
+            assert!(fee.abs_diff(fees::native_fee(weight) / fees::TRB_PRICE_IN_GLMR) <= 1);
+            fee
+        });
+        // no glmr is charged
+        balances.assert_deltas(&[("pallet derivative account", Asset::XcTrb, -(fee as i128))]);
+    }
+
+    #[test]
+    fn reports_charge_contract_accounts_according_to_fee_model() {
+        use parachains::evm::contracts::{erc20, staking};
+        use parachains::{dispute::Dispute, oracle_consumer::contract_account};
+        init_tracing();
+
+        // create trb asset, deploy contracts and register oracle consumer parachain
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        parachains::scenario::Scenario::new().deploy().register();
+
+        // fund the accounts of the contracts on the oracle consumer parachain, which pay for reports
+        OracleConsumerParachain::execute_with(|| {
+            for contract in [*STAKING_CONTRACT_ADDRESS, *GOVERNANCE_CONTRACT_ADDRESS] {
+                parachains::oracle_consumer::set_balance(
+                    contract_account(contract),
+                    10 * 10u128.pow(12),
+                );
+            }
+        });
+
+        // stake twice the minimum, reported as a new staker
+        let (_, stake_fee) = reported(|| {
+            EvmParachain::execute_with(|| {
+                staking::mint(
+                    *parachains::evm::XCTRB_ASSET_ID,
+                    Keyring::Bob.address(),
+                    amount * 2,
+                );
+                erc20::approve(
+                    Keyring::Bob.address(),
+                    *STAKING_CONTRACT_ADDRESS,
+                    amount * 2,
+                );
+                staking::deposit_parachain_stake(
+                    Keyring::Bob.address(),
+                    OracleConsumerParachain::para_id(),
+                    Keyring::Bob.account().to_raw_vec(),
+                    amount * 2,
+                );
+            })
+        });
+
+        // request withdrawal of half the stake and withdraw once unlocked
+        let (_, withdraw_request_fee) = reported(|| {
+            EvmParachain::execute_with(|| {
+                staking::request_parachain_stake_withdraw(
+                    Keyring::Bob.address(),
+                    OracleConsumerParachain::para_id(),
+                    amount,
+                )
+            })
+        });
+        parachains::dispute::advance_time((7 * DAYS) + 1);
+        let (_, withdraw_fee) = reported(|| {
+            EvmParachain::execute_with(|| {
+                staking::withdraw_parachain_stake(
+                    Keyring::Bob.address(),
+                    OracleConsumerParachain::para_id(),
+                )
+            })
+        });
+        OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::System;
+            System::assert_has_event(
+                tellor::Event::StakeWithdrawnReported {
+                    staker: Keyring::Bob.account(),
+                }
+                .into(),
+            );
+        });
+
+        // submit value and dispute it, reported as a slash of the remaining stake
+        let (query_id, timestamp) = OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            )
+        });
+        let (dispute, slash_fee) = reported(|| {
+            Dispute::begin(
+                Keyring::Dave.account(),
+                Keyring::Dave.address(),
+                query_id,
+                timestamp,
+            )
+        });
+        dispute.assert_begun_on_evm_parachain(Keyring::Bob.address(), amount);
+
+        // tally and execute votes, each reported as a vote result
+        dispute.advance_to_end_of_voting_period();
+        let (_, tally_fee) = reported(|| {
+            dispute.tally(
+                VoteResult::Invalid,
+                Keyring::Dave.address(),
+                Keyring::Bob.address(),
+            )
+        });
+        let (_, execute_fee) = reported(|| dispute.execute(VoteResult::Invalid));
+
+        // the contract accounts must be pre-funded to cover the fees of all reports
+        tracing::info!(
+            target: "xcm",
+            stake_fee,
+            withdraw_request_fee,
+            withdraw_fee,
+            slash_fee,
+            tally_fee,
+            execute_fee,
+            "fees charged to contract accounts"
+        );
+    }
+
+    #[test]
+    fn tellor_messages_charge_derivative_account_according_to_fee_model() {
+        init_tracing();
+
+        // create trb asset and deploy contracts
+        EvmParachain::execute_with(|| {
+            use parachains::{evm::contracts::*, evm::ALITH};
+            // create asset
+            parachains::evm::create_xctrb_asset();
+            // deploy contracts
+            registry::deploy();
+            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
+            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
+            // init contracts with addresses
+            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
+            governance::init(*STAKING_CONTRACT_ADDRESS);
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        let (_, registration_fee) =
+            charged(|| parachains::oracle_consumer::register(EvmParachain::para_id()));
+
+        // mint, approve and stake trb in staking contract for oracle consumer parachain
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::{erc20, staking};
+            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+            let amount =
+                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
+            staking::deposit_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+                Keyring::Bob.account().to_raw_vec(),
+                amount,
+            );
+        });
+
+        // submit value to oracle consumer parachain and then begin dispute
+        let (query_id, timestamp) = OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            )
+        });
+        let (dispute_id, dispute_fee) = charged(|| {
+            use oracle_consumer_runtime::{Runtime, RuntimeOrigin, Tellor};
+            assert_ok!(Tellor::begin_dispute(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                query_id,
+                timestamp,
+                Some(Keyring::Dave.address())
+            ));
+            parachains::dispute::dispute_id(
+                <Runtime as tellor::Config>::ParachainId::get(),
+                query_id,
+                timestamp,
+            )
+        });
+
+        // cast vote and send votes after parachain voting cut-off
+        let (_, vote_fee) = charged(|| {
+            use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
+            assert_ok!(Tellor::vote(
+                RuntimeOrigin::signed(Keyring::Bob.account()),
+                dispute_id,
+                Some(false)
+            ));
+            parachains::oracle_consumer::advance_time((11 * HOURS) + 1);
+            assert_ok!(Tellor::send_votes(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                5
+            ));
+        });
+
+        // the derivative account must be pre-funded to cover the fees of all messages
+        tracing::info!(
+            target: "xcm",
+            registration_fee,
+            dispute_fee,
+            vote_fee,
+            "fees charged to pallet derivative account"
+        );
+        assert!(
+            registration_fee + dispute_fee + vote_fee
+                < parachains::evm::PALLET_DERIVATIVE_ACCOUNT_FUNDING
+        );
+    }
+}
+
+mod insufficient_fees {
+    use super::*;
+    use parachains::evm::{fees, set_balance, transaction::balance_of};
+
+    #[test]
+    fn registration_fails_with_drained_derivative_account_until_topped_up() {
+        init_tracing();
+
+        // deploy parachain registry contract to evm parachain and drain pallet derivative account
+        let since = EvmParachain::execute_with(|| {
+            parachains::evm::contracts::registry::deploy();
+            set_balance(*PALLET_DERIVATIVE_ACCOUNT, 0);
+            moonbeam_runtime::System::events().len()
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // ensure message failed as fees could not be withdrawn, so no assets were trapped
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            assert!(matches!(
+                fees::failed_xcm_errors(since).as_slice(),
+                [xcm::v3::Error::FailedToTransactAsset(_)]
+            ));
+            assert!(fees::trapped_assets(since).is_empty());
+            assert_eq!(
+                registry::get_by_id(OracleConsumerParachain::para_id()).owner,
+                Address::zero()
+            );
+        });
+
+        // top up pallet derivative account and register again
+        let since = EvmParachain::execute_with(|| {
+            set_balance(
+                *PALLET_DERIVATIVE_ACCOUNT,
+                parachains::evm::PALLET_DERIVATIVE_ACCOUNT_FUNDING,
+            );
+            moonbeam_runtime::System::events().len()
+        });
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // ensure registration now stored
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            assert!(fees::failed_xcm_errors(since).is_empty());
+            registry::assert_parachain_registered_event(
+                *PALLET_DERIVATIVE_ACCOUNT,
+                OracleConsumerParachain::para_id(),
+                *PALLET_DERIVATIVE_ACCOUNT,
+            );
+            assert_eq!(
+                registry::get_by_id(OracleConsumerParachain::para_id()).owner,
+                *PALLET_DERIVATIVE_ACCOUNT
+            );
+        });
+    }
+
+    #[test]
+    fn dispute_not_begun_with_drained_derivative_account() {
+        init_tracing();
+
+        // create trb asset and deploy contracts
+        EvmParachain::execute_with(|| {
+            use parachains::{evm::contracts::*, evm::ALITH};
+            // create asset
+            parachains::evm::create_xctrb_asset();
+            // deploy contracts
+            registry::deploy();
+            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
+            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
+            // init contracts with addresses
+            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
+            governance::init(*STAKING_CONTRACT_ADDRESS);
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+
+        // mint, approve and stake trb in staking contract for oracle consumer parachain
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::{erc20, staking};
+            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
+            staking::deposit_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+                Keyring::Bob.account().to_raw_vec(),
+                amount,
+            );
+        });
+
+        // submit value to oracle consumer parachain
+        let (query_id, timestamp) = OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            )
+        });
+
+        // drain pallet derivative account
+        let since = EvmParachain::execute_with(|| {
+            set_balance(*PALLET_DERIVATIVE_ACCOUNT, 0);
+            moonbeam_runtime::System::events().len()
+        });
+
+        // begin dispute on oracle consumer parachain
+        let dispute_id = OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::{Runtime, RuntimeOrigin};
+            assert_ok!(Tellor::begin_dispute(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                query_id,
+                timestamp,
+                Some(Keyring::Dave.address())
+            ));
+            parachains::dispute::dispute_id(
+                <Runtime as tellor::Config>::ParachainId::get(),
+                query_id,
+                timestamp,
+            )
+        });
+
+        // ensure dispute not begun on evm parachain and reporter not slashed, as fees could not be
+        // withdrawn
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::{erc20, governance};
+            assert!(matches!(
+                fees::failed_xcm_errors(since).as_slice(),
+                [xcm::v3::Error::FailedToTransactAsset(_)]
+            ));
+            assert!(fees::trapped_assets(since).is_empty());
+            assert_eq!(governance::get_vote_rounds(dispute_id), 0.into());
+            assert_eq!(erc20::balance_of(*STAKING_CONTRACT_ADDRESS), amount.into());
+            assert_eq!(erc20::balance_of(Keyring::Dave.address()), 0.into());
+        });
+    }
+
+    #[test]
+    fn underfunded_registration_traps_fees_until_retried_and_claimed() {
+        use parachains::oracle_consumer::{capture_xcm, send_to_evm_parachain};
+        init_tracing();
+
+        // deploy parachain registry contract to evm parachain
+        EvmParachain::execute_with(|| {
+            parachains::evm::contracts::registry::deploy();
+        });
+
+        // capture the registration message sent by the tellor pallet, without sending it
+        let [mut message]: [Xcm<()>; 1] = OracleConsumerParachain::execute_with(|| {
+            capture_xcm(|| parachains::oracle_consumer::register(EvmParachain::para_id()))
+        })
+        .try_into()
+        .expect("a single message");
+        // the origin is descended into the tellor pallet again when resent
+        assert!(matches!(message.0.remove(0), DescendOrigin(_)));
+
+        // underfund the message, withdrawing only half the fee required for its weight
+        let (fee_asset, balance, since, required) = EvmParachain::execute_with(|| {
+            let mut weighed = message.clone();
+            weighed.0.insert(
+                0,
+                DescendOrigin(X1(PalletInstance(
+                    <Tellor as frame_support::traits::PalletInfoAccess>::index() as u8,
+                ))),
+            );
+            (
+                fees::native_asset(),
+                balance_of(*PALLET_DERIVATIVE_ACCOUNT),
+                moonbeam_runtime::System::events().len(),
+                fees::native_fee(fees::message_weight(weighed)),
+            )
+        });
+        let withdrawn = required / 2;
+        for instruction in message.0.iter_mut() {
+            match instruction {
+                WithdrawAsset(assets) => *assets = MultiAsset::from((fee_asset, withdrawn)).into(),
+                BuyExecution { fees, .. } => *fees = (fee_asset, withdrawn).into(),
+                _ => {}
+            }
+        }
+        OracleConsumerParachain::execute_with(|| send_to_evm_parachain(message));
+
+        // ensure registration failed with the withdrawn fees trapped, with the tellor pallet as
+        // origin
+        let trapped = EvmParachain::execute_with(|| {
+            use frame_support::traits::PalletInfoAccess;
+            use parachains::evm::contracts::registry;
+            assert!(matches!(
+                fees::failed_xcm_errors(since).as_slice(),
+                [xcm::v3::Error::TooExpensive]
+            ));
+            let [(origin, trapped)]: [_; 1] = fees::trapped_assets(since)
+                .try_into()
+                .expect("a single trap");
+            assert_eq!(
+                origin,
+                MultiLocation::new(
+                    1,
+                    X2(
+                        Parachain(OracleConsumerParachain::para_id().into()),
+                        PalletInstance(<Tellor as PalletInfoAccess>::index() as u8)
+                    )
+                )
+            );
+            let expected: MultiAssets = MultiAsset::from((fee_asset, withdrawn)).into();
+            assert_eq!(trapped, expected);
+            assert_eq!(balance_of(*PALLET_DERIVATIVE_ACCOUNT), balance - withdrawn);
+            assert_eq!(
+                registry::get_by_id(OracleConsumerParachain::para_id()).owner,
+                Address::zero()
+            );
+            trapped
+        });
+
+        // retry registration via the tellor pallet, which withdraws sufficient fees
+        let since = EvmParachain::execute_with(|| moonbeam_runtime::System::events().len());
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            assert!(fees::failed_xcm_errors(since).is_empty());
+            fees::assert_charged_for_executed_xcm(
+                *PALLET_DERIVATIVE_ACCOUNT,
+                balance - withdrawn,
+                since,
+            );
+            registry::assert_parachain_registered_event(
+                *PALLET_DERIVATIVE_ACCOUNT,
+                OracleConsumerParachain::para_id(),
+                *PALLET_DERIVATIVE_ACCOUNT,
+            );
+        });
+
+        // claim trapped fees back into pallet derivative account
+        let (balance, since) = EvmParachain::execute_with(|| {
+            (
+                balance_of(*PALLET_DERIVATIVE_ACCOUNT),
+                moonbeam_runtime::System::events().len(),
+            )
+        });
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::claim_trapped_assets(trapped)
+        });
+
+        // ensure claimed fees deposited, less the fees for claiming
+        EvmParachain::execute_with(|| {
+            assert!(fees::failed_xcm_errors(since).is_empty());
+            assert!(fees::trapped_assets(since).is_empty());
+            fees::assert_charged_for_executed_xcm(
+                *PALLET_DERIVATIVE_ACCOUNT,
+                balance + withdrawn,
+                since,
+            );
+        });
+    }
+
+    #[test]
+    fn drained_pallet_account_does_not_prevent_stake_reports_or_disputes() {
+        use parachains::oracle_consumer::pallet_account;
+        init_tracing();
+
+        // create trb asset and deploy contracts
+        EvmParachain::execute_with(|| {
+            use parachains::{evm::contracts::*, evm::ALITH};
+            // create asset
+            parachains::evm::create_xctrb_asset();
+            // deploy contracts
+            registry::deploy();
+            staking::deploy(*REGISTRY_CONTRACT_ADDRESS, *XCTRB_ADDRESS);
+            governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
+            // init contracts with addresses
+            staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
+            governance::init(*STAKING_CONTRACT_ADDRESS);
+        });
+
+        // register oracle consumer parachain with contracts on evm parachain via tellor pallet,
+        // then drain the pallet account
+        OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::register(EvmParachain::para_id());
+            parachains::oracle_consumer::set_balance(pallet_account(), 0);
+        });
+
+        // mint, approve and stake trb in staking contract for oracle consumer parachain
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::{erc20, staking};
+            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
+            staking::deposit_parachain_stake(
+                Keyring::Bob.address(),
+                OracleConsumerParachain::para_id(),
+                Keyring::Bob.account().to_raw_vec(),
+                amount,
+            );
+        });
+
+        // ensure stake reported, then submit value and begin dispute
+        let since = EvmParachain::execute_with(|| moonbeam_runtime::System::events().len());
+        let dispute_id = OracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::{Runtime, RuntimeOrigin, System};
+            System::assert_has_event(
+                tellor::Event::NewStakerReported {
+                    staker: Keyring::Bob.account(),
+                    amount: amount.into(),
+                    address: Keyring::Bob.address(),
+                }
+                .into(),
+            );
+            let (query_id, timestamp) = parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            );
+            assert_ok!(Tellor::begin_dispute(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                query_id,
+                timestamp,
+                Some(Keyring::Dave.address())
+            ));
+            parachains::dispute::dispute_id(
+                <Runtime as tellor::Config>::ParachainId::get(),
+                query_id,
+                timestamp,
+            )
+        });
+
+        // ensure dispute begun on evm parachain, as fees are paid by the pallet derivative account
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::governance;
+            assert!(fees::failed_xcm_errors(since).is_empty());
+            assert_eq!(governance::get_vote_rounds(dispute_id), 1.into());
+        });
+    }
+}
+
+mod invariants {
+    use super::*;
+    use parachains::{invariants::InvariantChecker, scenario::Scenario};
+    use query::{encode_price, Query};
+
+    fn staked_scenario() -> (Scenario, u128) {
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
+        let scenario = Scenario::new().deploy().register().stake(
+            Keyring::Bob.address(),
+            Keyring::Bob.account(),
+            amount,
+        );
+        (scenario, amount)
+    }
+
+    #[test]
+    fn parachains_agree_after_stake_and_dispute() {
+        init_tracing();
+        let (scenario, _) = staked_scenario();
+        let scenario = scenario
+            .report(&Query::spot_price("eth", "usd"), &encode_price("1800.0"))
+            .dispute(Keyring::Dave.account(), Keyring::Dave.address());
+
+        let checker = InvariantChecker::new()
+            .staker(Keyring::Bob.account(), Keyring::Bob.address())
+            .dispute(scenario.current_dispute().id);
+        assert_eq!(checker.check(), vec![]);
+        checker.assert_consistent();
+
+        // ensure both parachains also agree on the result once tallied
+        scenario
+            .vote(&[
+                (Keyring::Dave.account(), Some(true)),
+                (Keyring::Bob.account(), Some(false)),
+            ])
+            .advance(parachains::dispute::PARACHAIN_VOTING_CUT_OFF + 1)
+            .advance((1 * DAYS) + 1)
+            .tally(VoteResult::Invalid);
+        assert_eq!(checker.check(), vec![]);
+    }
+
+    #[test]
+    fn dispute_begun_only_on_evm_parachain_is_a_mismatch() {
+        init_tracing();
+        let (scenario, amount) = staked_scenario();
+
+        // report value, then begin dispute of reported value directly on evm parachain as
+        // parachain owner, unknown to tellor pallet
+        let value = encode_price("1800.0");
+        let scenario = scenario.report(&Query::spot_price("eth", "usd"), &value);
+        let (query_id, timestamp) = scenario.last_report();
+        let dispute_id = parachains::dispute::dispute_id(
+            OracleConsumerParachain::para_id(),
+            query_id,
+            timestamp,
+        );
+        EvmParachain::execute_with(|| {
+            parachains::evm::contracts::governance::begin_parachain_dispute(
+                query_id,
+                timestamp,
+                value,
+                Keyring::Bob.address(),
+                Keyring::Dave.address(),
+                amount / 10,
+                amount,
+            );
+        });
+
+        let mismatches = InvariantChecker::new()
+            .staker(Keyring::Bob.account(), Keyring::Bob.address())
+            .dispute(dispute_id)
+            .check();
+        assert_eq!(mismatches.len(), 1, "{mismatches:?}");
+        assert_eq!(
+            mismatches[0].subject,
+            format!("vote rounds of dispute {dispute_id:?}")
+        );
+        assert_eq!(mismatches[0].evm, "1");
+        assert_eq!(mismatches[0].oracle_consumer, "0");
+    }
+}
+
+mod query_data {
+    use super::*;
+    use query::Query;
+    use sp_core::H256;
+    use std::str::FromStr;
+    use tellor::U256;
+
+    #[test]
+    fn spot_price_query_id_matches_tellor_data_specs() {
+        // https://github.com/tellor-io/dataSpecs/blob/main/types/SpotPrice.md
+        assert_eq!(
+            Query::spot_price("eth", "usd").query_id(),
+            H256::from_str("83a7f3d48786ac2667503a61e8c415438ed2922eb86a2906e4ee66d9a2ce4992")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn query_data_encodes_query_type_and_parameters() {
+        let query_data = Query::tellor_rng(1_682_950_000).query_data();
+        let tokens = ethabi::decode(
+            &[ethabi::ParamType::String, ethabi::ParamType::Bytes],
+            &query_data,
+        )
+        .unwrap();
+        assert_eq!(tokens[0], ethabi::Token::String("TellorRNG".to_string()));
+        assert_eq!(
+            tokens[1],
+            ethabi::Token::Bytes(ethabi::encode(&[ethabi::Token::Uint(
+                1_682_950_000u64.into()
+            )]))
+        );
+    }
+
+    #[test]
+    fn values_round_trip() {
+        let price = query::encode_price("4.39");
+        assert_eq!(
+            query::decode_uint(&price),
+            U256::from(4_390_000_000_000_000_000u128)
+        );
+        assert_eq!(
+            query::parse_price("1800.5"),
+            Ok(U256::from(1_800_500_000_000_000_000_000u128))
+        );
+        assert_eq!(
+            query::parse_price("15"),
+            Ok(U256::from(15 * 10u128.pow(18)))
+        );
+        for invalid in ["", ".5", "-1", "1e18", "0.0000000000000000001"] {
+            assert!(query::parse_price(invalid).is_err(), "{invalid} is invalid");
+        }
+        let random = H256::repeat_byte(7);
+        assert_eq!(query::decode_random(&query::encode_random(random)), random);
+        let response = query::encode_evm_call_response(vec![1, 2, 3], 1_682_950_000);
+        assert_eq!(
+            query::decode_evm_call_response(&response),
+            (vec![1, 2, 3], 1_682_950_000)
+        );
+    }
+
+    #[test]
+    fn standard_queries_fit_max_query_data_length() {
+        use oracle_consumer_runtime::Runtime;
+        type QueryData =
+            frame_support::BoundedVec<u8, <Runtime as tellor::Config>::MaxQueryDataLength>;
+
+        let max = <Runtime as tellor::Config>::MaxQueryDataLength::get() as usize;
+        for query in [
+            Query::spot_price("trb", "usd"),
+            Query::numeric_api_response(
+                "https://api.coingecko.com/api/v3/simple/price?ids=tellor&vs_currencies=usd",
+                "tellor, usd",
+            ),
+            Query::tellor_rng(1_682_950_000),
+            Query::evm_call(1, *XCTRB_ADDRESS, vec![0u8; 68]),
+        ] {
+            let query_data = query.query_data();
+            assert!(
+                query_data.len() <= max,
+                "{} exceeds limit",
+                query.query_type()
+            );
+            assert!(QueryData::try_from(query_data).is_ok());
+        }
+
+        // calldata is unbounded, so evm calls may exceed the limit
+        let query = Query::evm_call(1, *XCTRB_ADDRESS, vec![0u8; max]);
+        assert!(QueryData::try_from(query.query_data()).is_err());
     }
 
     #[test]
-    fn tally_votes_on_evm_parachain_reports_result_to_consumer_parachain() {
+    fn submit_value_for_standard_queries_works() {
         init_tracing();
 
         // create trb asset and deploy contracts
@@ -853,7 +3332,6 @@ mod governance {
             governance::deploy(*REGISTRY_CONTRACT_ADDRESS, *ALITH);
             // init contracts with addresses
             staking::init(*GOVERNANCE_CONTRACT_ADDRESS);
-            governance::init(*STAKING_CONTRACT_ADDRESS);
         });
 
         // register oracle consumer parachain with contracts on evm parachain via tellor pallet
@@ -861,114 +3339,291 @@ mod governance {
             parachains::oracle_consumer::register(EvmParachain::para_id())
         });
 
-        // mint, approve and stake trb in staking contract for oracle consumer parachain
+        // stake for reporter
+        let mut reporter = parachains::reporter::Reporter::from(Keyring::Bob);
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
-            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            let amount =
-                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
-            staking::deposit_parachain_stake(
-                *BALTHAZAR,
-                OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
-                amount,
-            );
-        });
+            reporter.stake(
+                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get(),
+            )
+        });
+
+        // report a value for each standard query type
+        for (query, value) in [
+            (Query::spot_price("trb", "usd"), query::encode_price("15.7")),
+            (
+                Query::numeric_api_response("https://api.example.com/price", "price"),
+                query::encode_uint(42.into()),
+            ),
+            (
+                Query::tellor_rng(1_682_950_000),
+                query::encode_random(H256::repeat_byte(7)),
+            ),
+            (
+                Query::evm_call(1, *XCTRB_ADDRESS, vec![0x18, 0x16, 0x0d, 0xdd]),
+                query::encode_evm_call_response(
+                    ethabi::encode(&[ethabi::Token::Uint(1_000.into())]),
+                    1_682_950_000,
+                ),
+            ),
+        ] {
+            // tip query, so that query data is stored
+            OracleConsumerParachain::execute_with(|| {
+                use oracle_consumer_runtime::RuntimeOrigin;
+                assert_ok!(Tellor::tip(
+                    RuntimeOrigin::signed(Keyring::Charlie.account()),
+                    query.query_id(),
+                    1_000,
+                    query.query_data().try_into().unwrap()
+                ));
+            });
+            let (query_id, _) = reporter.submit_value(&query.query_data(), &value);
+            assert_eq!(query_id, query.query_id());
+            OracleConsumerParachain::execute_with(|| {
+                use oracle_consumer_runtime::System;
+                System::assert_has_event(
+                    tellor::Event::TipAdded {
+                        query_id,
+                        amount: 1_000,
+                        query_data: query.query_data().try_into().unwrap(),
+                        tipper: Keyring::Charlie.account(),
+                    }
+                    .into(),
+                );
+            });
+        }
+    }
+}
 
-        // create tip to be able to have vote counted as user
-        let tip = 1_000_000_000_000;
-        let query_data = b"hello tellor";
-        OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
-            assert_ok!(Tellor::tip(
-                RuntimeOrigin::signed(DAVE.clone()),
-                Keccak256::hash(query_data.as_slice()),
-                tip,
-                query_data.to_vec().try_into().unwrap()
-            ));
-        });
+mod scenarios {
+    use super::*;
+    use std::{
+        fs,
+        path::{Path, PathBuf},
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    fn scenarios() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("scenarios")
+    }
 
-        // submit value to oracle consumer parachain, begin dispute and cast votes
-        let dispute_id = OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::{Runtime, RuntimeOrigin, Tellor};
-            // submit value
-            let (query_id, timestamp) =
-                parachains::oracle_consumer::submit_value(BOB.clone(), query_data, b"hey!");
-            // begin dispute
-            assert_ok!(Tellor::begin_dispute(
-                RuntimeOrigin::signed(DAVE.clone()),
-                query_id,
-                timestamp,
-                Some(*DOROTHY)
-            ));
-            let dispute_id = Keccak256::hash(&ethabi::encode(&[
-                ethabi::Token::Uint(<Runtime as tellor::Config>::ParachainId::get().into()),
-                ethabi::Token::FixedBytes(query_id.0.into()),
-                ethabi::Token::Uint(timestamp.into()),
-            ]));
-            // cast votes
-            assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(DAVE.clone()),
-                dispute_id,
-                Some(true) // for
-            ));
-            assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(BOB.clone()),
-                dispute_id,
-                Some(false) // against
-            ));
-            dispute_id
-        });
+    // a scenario written to a temporary file unique to the test, removed when dropped
+    struct TempFile(PathBuf);
 
-        // advance time until parachain voting cut-off
-        EvmParachain::execute_with(|| parachains::evm::advance_time((11 * HOURS) + 1));
-        OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::advance_time((11 * HOURS) + 1);
-            // send votes via on_initialize hook
-            Tellor::on_initialize(0)
-        });
+    impl std::ops::Deref for TempFile {
+        type Target = Path;
 
-        // advance time until voting cut-off
-        EvmParachain::execute_with(|| parachains::evm::advance_time((1 * DAYS) + 1));
-        OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::advance_time((1 * DAYS) + 1);
-            // send votes via on_initialize hook
-            Tellor::on_initialize(0)
-        });
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
 
-        // tally votes on evm parachain
-        EvmParachain::execute_with(|| {
-            use parachains::evm::*;
-            contracts::governance::tally_votes(*ALITH, dispute_id);
-            contracts::governance::assert_vote_tallied_event(
-                dispute_id,
-                VoteResult::Invalid as u8,
-                *DOROTHY,
-                *BALTHAZAR,
-            );
-        });
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
 
-        // ensure tally reported to tellor pallet on oracle consumer parachain
-        OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::System;
-            System::assert_has_event(
-                tellor::Event::VoteTallied {
-                    dispute_id,
-                    result: VoteResult::Invalid,
-                    initiator: DAVE.clone(),
-                    reporter: BOB.clone(),
-                }
-                .into(),
-            );
-        });
+    // writes the scenario to a uniquely named temporary file with the extension of the name, as
+    // tests run concurrently within the process and may be run by several processes at once
+    fn write(name: &str, contents: &str) -> TempFile {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "tellor-scenario-{}-{}-{name}",
+            std::process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::write(&path, contents).unwrap();
+        TempFile(path)
     }
 
     #[test]
-    fn execute_vote_on_evm_parachain_reports_to_consumer_parachain() {
+    fn example_scenarios_pass() {
+        init_tracing();
+        for file in ["dispute_executed.yaml", "tipped_report.json"] {
+            if let Err(e) = run_scenario_file(&scenarios().join(file)) {
+                panic!("scenario {file} failed: {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn unmet_expectation_fails_scenario() {
+        init_tracing();
+        let path = write(
+            "unmet_expectation.yaml",
+            "steps:
+  - deploy
+  - register
+  - expect:
+      oracle_consumer_events: [NewStakerReported]
+",
+        );
+        let error = run_scenario_file(&path).unwrap_err();
+        assert!(error.starts_with("step 3"), "{error}");
+        assert!(
+            error.contains("expected event NewStakerReported not emitted"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn failing_step_fails_scenario() {
+        init_tracing();
+        // reporting requires a stake
+        let path = write(
+            "failing_step.json",
+            r#"{ "steps": ["deploy", "register", { "report": {
+                "query": { "spot_price": { "asset": "eth", "currency": "usd" } },
+                "value": { "price": "1800.0" } } }] }"#,
+        );
+        let error = run_scenario_file(&path).unwrap_err();
+        // ensure panics within steps are reported with the failing step
+        assert!(error.starts_with("step 3 (Report"), "{error}");
+        assert!(error.contains("a reporter must first be staked"), "{error}");
+    }
+
+    #[test]
+    fn next_dispute_round_by_another_initiator_fails_scenario() {
+        init_tracing();
+        let path = write(
+            "another_initiator.yaml",
+            "steps:
+  - deploy
+  - register
+  - stake: { staker: BALTHAZAR, account: BOB }
+  - report:
+      query:
+        spot_price: { asset: eth, currency: usd }
+      value:
+        price: \"1800.0\"
+  - dispute: { initiator: DAVE, beneficiary: DOROTHY }
+  - dispute: { initiator: CHARLIE, beneficiary: CHARLETH }
+",
+        );
+        let error = run_scenario_file(&path).unwrap_err();
+        assert!(error.starts_with("step 6 (Dispute"), "{error}");
+        assert!(
+            error.contains("next round of dispute must be begun by its initiator"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn invalid_scenario_file_is_rejected() {
+        let path = write("unknown_step.yaml", "steps:\n  - teleport\n");
+        let error = run_scenario_file(&path).unwrap_err();
+        assert!(error.starts_with("could not parse"), "{error}");
+
+        let path = write(
+            "unknown_actor.yaml",
+            "steps:\n  - stake: { staker: EVE, account: BOB }\n",
+        );
+        let error = run_scenario_file(&path).unwrap_err();
+        assert!(
+            error.contains("unknown evm parachain address EVE"),
+            "{error}"
+        );
+
+        let path = write("scenario.txt", "steps: []");
+        let error = run_scenario_file(&path).unwrap_err();
+        assert!(error.starts_with("unsupported scenario file"), "{error}");
+    }
+}
+
+mod sequences {
+    use super::*;
+    use parachains::sequence::{operations, Sequence};
+    use proptest::prelude::*;
+
+    // each case runs the full network, so far fewer cases are run than the proptest default
+    fn cases() -> u32 {
+        std::env::var("PROPTEST_CASES")
+            .ok()
+            .and_then(|c| c.parse().ok())
+            .unwrap_or(8)
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig { cases: cases(), ..ProptestConfig::default() })]
+
+        #[test]
+        fn random_operation_sequences_keep_chains_in_agreement(operations in operations(25)) {
+            init_tracing();
+            Sequence::new().run(&operations);
+        }
+    }
+
+    #[test]
+    fn slashed_stake_is_reflected_on_both_chains() {
+        use parachains::sequence::Operation::*;
+        init_tracing();
+
+        // stake, report and dispute, slashing locked stake before staked
+        Sequence::new().run(&[
+            Stake {
+                reporter: 0,
+                quarters: 8,
+            },
+            Report {
+                reporter: 0,
+                query: 0,
+            },
+            RequestWithdraw {
+                reporter: 0,
+                quarters: 2,
+            },
+            Dispute { report: 0 },
+            Vote {
+                voter: 2,
+                supports: Some(true),
+            },
+            Advance { hours: 12 },
+            Advance { hours: 48 },
+        ]);
+    }
+
+    #[test]
+    fn reporting_again_keeps_dispute_window_on_both_chains() {
+        use parachains::sequence::Operation::*;
         init_tracing();
 
+        // a second report by the same reporter waits for the reporting lock, which must advance
+        // the clocks of both parachains so that the first report is no longer disputable whilst
+        // the second is
+        let applied = Sequence::new().run(&[
+            Stake {
+                reporter: 0,
+                quarters: 4,
+            },
+            Report {
+                reporter: 0,
+                query: 0,
+            },
+            Report {
+                reporter: 0,
+                query: 0,
+            },
+            Dispute { report: 0 },
+            Dispute { report: 1 },
+            Vote {
+                voter: 2,
+                supports: Some(true),
+            },
+            Advance { hours: 12 },
+            Advance { hours: 48 },
+        ]);
+        assert_eq!(applied, [true, true, true, false, true, true, true, true]);
+    }
+}
+
+mod second_consumer {
+    use super::*;
+    use parachains::dispute::dispute_id;
+    use parachains::evm::pallet_derivative_account;
+    use parachains::oracle_consumer::{PARA_ID, SECOND_PARA_ID};
+
+    // deploys contracts and registers both oracle consumer parachains
+    fn setup() {
         // create trb asset and deploy contracts
         EvmParachain::execute_with(|| {
             use parachains::{evm::contracts::*, evm::ALITH};
@@ -983,126 +3638,224 @@ mod governance {
             governance::init(*STAKING_CONTRACT_ADDRESS);
         });
 
-        // register oracle consumer parachain with contracts on evm parachain via tellor pallet
+        // register both oracle consumer parachains with contracts on evm parachain via tellor pallet
         OracleConsumerParachain::execute_with(|| {
             parachains::oracle_consumer::register(EvmParachain::para_id())
         });
+        SecondOracleConsumerParachain::execute_with(|| {
+            // ensure pallet configured with id of second parachain, rather than inheriting it
+            assert_eq!(
+                <oracle_consumer_runtime::Runtime as tellor::Config>::ParachainId::get(),
+                SECOND_PARA_ID
+            );
+            parachains::oracle_consumer::register(EvmParachain::para_id())
+        });
+    }
 
-        // mint, approve and stake trb in staking contract for oracle consumer parachain
+    // stakes the minimum amount for bob on the parachain, from the staker address
+    fn stake(para_id: u32, staker: Address) -> u128 {
+        let amount =
+            <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
-            let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
-            let amount =
-                <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
+            use parachains::evm::contracts::{erc20, staking};
+            staking::mint(*parachains::evm::XCTRB_ASSET_ID, staker, amount);
+            erc20::approve(staker, *STAKING_CONTRACT_ADDRESS, amount);
             staking::deposit_parachain_stake(
-                *BALTHAZAR,
-                OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                staker,
+                para_id,
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
         });
+        amount
+    }
 
-        // create tip to be able to have vote counted as user
-        let tip = 1_000_000_000_000;
-        let query_data = b"hello tellor";
-        OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
-            assert_ok!(Tellor::tip(
-                RuntimeOrigin::signed(DAVE.clone()),
-                Keccak256::hash(query_data.as_slice()),
-                tip,
-                query_data.to_vec().try_into().unwrap()
-            ));
-        });
+    fn stake_reported(staker: Address) -> bool {
+        oracle_consumer_runtime::System::events().iter().any(|r| {
+            matches!(
+                &r.event,
+                oracle_consumer_runtime::RuntimeEvent::Tellor(tellor::Event::NewStakerReported {
+                    address,
+                    ..
+                }) if *address == staker
+            )
+        })
+    }
 
-        // submit value to oracle consumer parachain, begin dispute and cast votes
-        let dispute_id = OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::{Runtime, RuntimeOrigin, Tellor};
-            // submit value
-            let (query_id, timestamp) =
-                parachains::oracle_consumer::submit_value(BOB.clone(), query_data, b"hey!");
-            // begin dispute
-            assert_ok!(Tellor::begin_dispute(
-                RuntimeOrigin::signed(DAVE.clone()),
-                query_id,
-                timestamp,
-                Some(*DOROTHY)
-            ));
-            let dispute_id = Keccak256::hash(&ethabi::encode(&[
-                ethabi::Token::Uint(<Runtime as tellor::Config>::ParachainId::get().into()),
-                ethabi::Token::FixedBytes(query_id.0.into()),
-                ethabi::Token::Uint(timestamp.into()),
-            ]));
-            // cast votes
-            assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(DAVE.clone()),
-                dispute_id,
-                Some(true) // for
-            ));
-            assert_ok!(Tellor::vote(
-                RuntimeOrigin::signed(BOB.clone()),
-                dispute_id,
-                Some(false) // against
-            ));
-            dispute_id
-        });
+    fn slash_reported() -> bool {
+        oracle_consumer_runtime::System::events().iter().any(|r| {
+            matches!(
+                &r.event,
+                oracle_consumer_runtime::RuntimeEvent::Tellor(tellor::Event::SlashReported { .. })
+            )
+        })
+    }
 
-        // advance time until parachain voting cut-off
-        EvmParachain::execute_with(|| parachains::evm::advance_time((11 * HOURS) + 1));
-        OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::advance_time((11 * HOURS) + 1);
-            // send votes via on_initialize hook
-            Tellor::on_initialize(0)
+    #[test]
+    fn both_parachains_register_with_own_derivative_accounts() {
+        init_tracing();
+        setup();
+
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::registry;
+            assert_eq!(
+                pallet_derivative_account(PARA_ID),
+                *PALLET_DERIVATIVE_ACCOUNT
+            );
+            let second_owner = pallet_derivative_account(SECOND_PARA_ID);
+            assert_ne!(second_owner, *PALLET_DERIVATIVE_ACCOUNT);
+            registry::assert_parachain_registered_event(second_owner, SECOND_PARA_ID, second_owner);
+            assert_eq!(
+                registry::get_by_id(PARA_ID).owner,
+                *PALLET_DERIVATIVE_ACCOUNT
+            );
+            assert_eq!(registry::get_by_id(SECOND_PARA_ID).owner, second_owner);
         });
+    }
 
-        // advance time until voting cut-off
-        EvmParachain::execute_with(|| parachains::evm::advance_time((1 * DAYS) + 1));
+    #[test]
+    fn stakes_are_only_reported_to_their_parachain() {
+        init_tracing();
+        setup();
+
+        // stake for each parachain from a different staker address
+        let amount = stake(PARA_ID, Keyring::Bob.address());
+        stake(SECOND_PARA_ID, Keyring::Charlie.address());
+
+        // ensure each parachain only received its own stake
         OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::advance_time((1 * DAYS) + 1);
-            // send votes via on_initialize hook
-            Tellor::on_initialize(0)
+            oracle_consumer_runtime::System::assert_has_event(
+                tellor::Event::NewStakerReported {
+                    staker: Keyring::Bob.account(),
+                    amount: amount.into(),
+                    address: Keyring::Bob.address(),
+                }
+                .into(),
+            );
+            assert!(!stake_reported(Keyring::Charlie.address()));
+        });
+        SecondOracleConsumerParachain::execute_with(|| {
+            oracle_consumer_runtime::System::assert_has_event(
+                tellor::Event::NewStakerReported {
+                    staker: Keyring::Bob.account(),
+                    amount: amount.into(),
+                    address: Keyring::Charlie.address(),
+                }
+                .into(),
+            );
+            assert!(!stake_reported(Keyring::Bob.address()));
         });
+    }
+
+    #[test]
+    fn disputes_are_isolated_between_parachains() {
+        init_tracing();
+        setup();
+        let amount = stake(PARA_ID, Keyring::Bob.address());
+        stake(SECOND_PARA_ID, Keyring::Charlie.address());
 
-        // tally votes on evm parachain
+        // submit the same value to both parachains
+        let (query_id, timestamp) = OracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            )
+        });
+        let (_, second_timestamp) = SecondOracleConsumerParachain::execute_with(|| {
+            parachains::oracle_consumer::submit_value(
+                Keyring::Bob.account(),
+                b"hello tellor",
+                b"hey!",
+            )
+        });
+
+        // ensure dispute ids do not collide
+        assert_ne!(
+            dispute_id(PARA_ID, query_id, timestamp),
+            dispute_id(SECOND_PARA_ID, query_id, timestamp)
+        );
+        let second_dispute_id = dispute_id(SECOND_PARA_ID, query_id, second_timestamp);
+
+        // dispute value on first parachain
+        let dispute = parachains::dispute::Dispute::begin(
+            Keyring::Dave.account(),
+            Keyring::Dave.address(),
+            query_id,
+            timestamp,
+        );
+        dispute.assert_begun_on_evm_parachain(Keyring::Bob.address(), amount);
+        dispute.vote(&[
+            (Keyring::Dave.account(), Some(true)),
+            (Keyring::Bob.account(), Some(false)),
+        ]);
+        dispute.advance_to_parachain_voting_cut_off();
+
+        // ensure dispute and votes only recorded for first parachain, with staker of second
+        // parachain not slashed
         EvmParachain::execute_with(|| {
-            use parachains::evm::*;
-            contracts::governance::tally_votes(*ALITH, dispute_id);
-            contracts::governance::assert_vote_tallied_event(
-                dispute_id,
-                VoteResult::Invalid as u8,
-                *DOROTHY,
-                *BALTHAZAR,
-            );
+            use parachains::evm::contracts::{erc20, governance};
+            assert_eq!(governance::get_vote_rounds(dispute.id), 1.into());
+            assert_eq!(governance::get_vote_rounds(second_dispute_id), 0.into());
+            // only stake of first parachain slashed
+            assert_eq!(erc20::balance_of(*STAKING_CONTRACT_ADDRESS), amount.into());
+            assert!(governance::did_vote(dispute.id, *PALLET_DERIVATIVE_ACCOUNT));
+            assert!(!governance::did_vote(
+                second_dispute_id,
+                pallet_derivative_account(SECOND_PARA_ID)
+            ));
         });
 
-        // advance time until voting cut-off
-        EvmParachain::execute_with(|| parachains::evm::advance_time((3 * DAYS) + 1));
-        OracleConsumerParachain::execute_with(|| {
-            parachains::oracle_consumer::advance_time((3 * DAYS) + 1);
+        // ensure slash and votes not reported to second parachain
+        SecondOracleConsumerParachain::execute_with(|| {
+            assert!(!slash_reported());
+            assert!(oracle_consumer_runtime::System::events()
+                .iter()
+                .all(|r| !matches!(
+                    &r.event,
+                    oracle_consumer_runtime::RuntimeEvent::Tellor(tellor::Event::NewDispute { .. })
+                )));
         });
 
-        // execute vote on evm parachain
-        EvmParachain::execute_with(|| {
-            use parachains::evm::*;
-            contracts::governance::execute_vote(*ALITH, dispute_id);
-            contracts::governance::assert_vote_executed_event(
-                dispute_id,
-                VoteResult::Invalid as u8,
-            );
+        // dispute and vote on value of second parachain, sending its votes once past the
+        // parachain voting cut-off
+        let tallies = EvmParachain::execute_with(|| {
+            parachains::evm::contracts::governance::get_vote_tallies(dispute.id, 1)
+        });
+        assert_ne!(tallies, Default::default());
+        SecondOracleConsumerParachain::execute_with(|| {
+            use oracle_consumer_runtime::{RuntimeOrigin, Tellor};
+            assert_ok!(Tellor::begin_dispute(
+                RuntimeOrigin::signed(Keyring::Dave.account()),
+                query_id,
+                second_timestamp,
+                Some(Keyring::Dave.address())
+            ));
+            assert_ok!(Tellor::vote(
+                RuntimeOrigin::signed(Keyring::Bob.account()),
+                second_dispute_id,
+                Some(true)
+            ));
+        });
+        let cut_off = parachains::dispute::PARACHAIN_VOTING_CUT_OFF + 1;
+        EvmParachain::execute_with(|| parachains::evm::advance_time(cut_off));
+        SecondOracleConsumerParachain::execute_with(|| {
+            use frame_support::traits::Hooks;
+            parachains::oracle_consumer::advance_time(cut_off);
+            oracle_consumer_runtime::Tellor::on_initialize(0);
         });
 
-        // ensure executed vote reported to tellor pallet on oracle consumer parachain
-        OracleConsumerParachain::execute_with(|| {
-            use oracle_consumer_runtime::System;
-            System::assert_has_event(
-                tellor::Event::VoteExecuted {
-                    dispute_id,
-                    result: VoteResult::Invalid,
-                }
-                .into(),
-            );
+        // ensure votes of second parachain only tallied for its own dispute
+        EvmParachain::execute_with(|| {
+            use parachains::evm::contracts::governance;
+            assert_eq!(governance::get_vote_rounds(second_dispute_id), 1.into());
+            assert!(governance::did_vote(
+                second_dispute_id,
+                pallet_derivative_account(SECOND_PARA_ID)
+            ));
+            let second_tallies = governance::get_vote_tallies(second_dispute_id, 1);
+            assert_ne!(second_tallies, Default::default());
+            assert_eq!(governance::get_vote_tallies(dispute.id, 1), tallies);
         });
     }
 }
@@ -1135,16 +3888,16 @@ mod using_tellor {
 
         // mint, approve and stake trb in staking contract for oracle consumer parachain
         EvmParachain::execute_with(|| {
-            use parachains::evm::contracts::staking;
+            use parachains::evm::contracts::{erc20, staking};
             let asset = u128::from_be_bytes(XCTRB_ADDRESS[4..].try_into().unwrap());
             let amount =
                 <oracle_consumer_runtime::Runtime as tellor::Config>::MinimumStakeAmount::get();
-            staking::mint(asset, *BALTHAZAR, amount);
-            staking::approve(*BALTHAZAR, asset, *STAKING_CONTRACT_ADDRESS, amount);
+            staking::mint(asset, Keyring::Bob.address(), amount);
+            erc20::approve(Keyring::Bob.address(), *STAKING_CONTRACT_ADDRESS, amount);
             staking::deposit_parachain_stake(
-                *BALTHAZAR,
+                Keyring::Bob.address(),
                 OracleConsumerParachain::para_id(),
-                BOB.to_raw_vec(),
+                Keyring::Bob.account().to_raw_vec(),
                 amount,
             );
         });
@@ -1154,17 +3907,17 @@ mod using_tellor {
             use oracle_consumer_runtime::{RuntimeOrigin, System, UsingTellor};
 
             // configure using-tellor pallet with price source
-            let query_data = b"DOT/USD";
-            let query_id = Keccak256::hash(query_data.as_slice());
+            let spot_price = query::Query::spot_price("dot", "usd");
+            let query_id = spot_price.query_id();
             assert_ok!(UsingTellor::configure(RuntimeOrigin::root(), query_id));
             System::assert_has_event(::using_tellor::Event::Configured { query_id }.into());
 
             // submit price to oracle
             let price = U256::from((4.39 * 10u64.pow(18) as f64) as u128);
             parachains::oracle_consumer::submit_value(
-                BOB.clone(),
-                query_data,
-                &ethabi::encode(&vec![ethabi::Token::Uint(price)]),
+                Keyring::Bob.account(),
+                &spot_price.query_data(),
+                &query::encode_uint(price),
             );
 
             // advance time, as using-tellor sample uses a delayed price to allow time for disputes
@@ -1173,13 +3926,13 @@ mod using_tellor {
             // do something using previously submitted oracle price
             let value = U256::from(10);
             assert_ok!(UsingTellor::do_something(
-                RuntimeOrigin::signed(CHARLIE.clone()),
+                RuntimeOrigin::signed(Keyring::Charlie.account()),
                 value
             ));
             System::assert_last_event(
                 ::using_tellor::Event::ValueStored {
                     value: price * value,
-                    who: CHARLIE.clone(),
+                    who: Keyring::Charlie.account(),
                 }
                 .into(),
             );